@@ -56,18 +56,8 @@ func main{
     } else {
         print_string('recursive case');
 
-        let (_, remainder) = felt_divmod(epoch_update.header.slot.low + 1, SYNC_COMMITTEE_PERIOD);
-        local is_committee_transition: felt;
-        if (remainder == 0) {
-            is_committee_transition = 1;
-        } else {
-            is_committee_transition = 0;
-        }
-        print_string('is_committee_transition');
-        print_felt_hex(is_committee_transition);
-
         with pow2_array, sha256_ptr {
-            let (epoch_update_output, next_committee_hash) = handle_recursive_case(epoch_update, program_hash, is_committee_transition);
+            let (epoch_update_output, next_committee_hash, is_committee_transition) = handle_recursive_case(epoch_update, program_hash);
         }
         print_string('confirmed epoch');
 
@@ -122,7 +112,7 @@ func handle_recursive_case{
     mul_mod_ptr: ModBuiltin*,
     sha256_ptr: felt*,
     pow2_array: felt*,
-}(epoch_update: EpochUpdate, program_hash: felt, is_committee_transition: felt) -> (EpochUpdateOutput, Uint256) {
+}(epoch_update: EpochUpdate, program_hash: felt) -> (EpochUpdateOutput, Uint256, felt) {
     alloc_locals;
 
     let (epoch_update_output) = run_epoch_update(epoch_update);
@@ -131,6 +121,21 @@ func handle_recursive_case{
     local expected_proof_output: CircuitOutput;
     %{ load_expected_proof_output() %}
 
+    // A header is signed by the committee of the period of `slot + 1`. The update is a committee
+    // transition if that period follows the signing period of the previous update. This also
+    // covers an empty last slot of a period, where the first slot of the next period is used.
+    let (signing_period, _) = felt_divmod(epoch_update_output.beacon_height + 1, SYNC_COMMITTEE_PERIOD);
+    let (previous_signing_period, _) = felt_divmod(expected_proof_output.beacon_height + 1, SYNC_COMMITTEE_PERIOD);
+    local is_committee_transition: felt;
+    if (signing_period == previous_signing_period) {
+        is_committee_transition = 0;
+    } else {
+        // Only the next committee is known, so a period can't be skipped
+        assert signing_period = previous_signing_period + 1;
+        is_committee_transition = 1;
+    }
+    print_string('is_committee_transition');
+    print_felt_hex(is_committee_transition);
 
     // Check that expected matches the committee hash that was used to sign
 
@@ -214,7 +219,7 @@ func handle_recursive_case{
     assert output_hash = expected_output_hash;    
     assert proof_program_hash = BOOTLOADER_PROGRAM_HASH;
 
-    return (epoch_update_output, expected_proof_output.next_committee_hash);
+    return (epoch_update_output, expected_proof_output.next_committee_hash, is_committee_transition);
 }
 
 func handle_genesis_case{
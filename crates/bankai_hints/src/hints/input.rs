@@ -7,10 +7,7 @@ use cairo_vm_base::vm::cairo_vm::vm::vm_core::VirtualMachine;
 use cairo_vm_base::vm::cairo_vm::vm::errors::hint_errors::HintError;
 use cairo_vm_base::vm::cairo_vm::types::exec_scope::ExecutionScopes;
 use cairo_vm_base::vm::cairo_vm::Felt252;
-use cairo_vm_base::cairo_type::CairoWritable;
-use cairo_vm_base::cairo_type::CairoType;
-
-use crate::types::RecursiveEpochInputsCairo;
+use cairo_types::{CairoType, RecursiveEpochInputsCairo};
 
 pub fn write_epoch_update_inputs(
     vm: &mut VirtualMachine,
@@ -35,7 +32,7 @@ pub fn write_epoch_update_inputs(
         &hint_data.ids_data,
         &hint_data.ap_tracking,
     )?;
-    let is_genesis = match &inputs.stone_proof {
+    let is_genesis = match &inputs.stark_proof {
         Some(_) => 0,
         None => 1,
     };
@@ -95,7 +92,7 @@ pub fn write_stone_proof_inputs(
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
     let inputs = exec_scopes.get_ref::<RecursiveEpochInputsCairo>("inputs")?;
-    if let Some(stone_proof) = &inputs.stone_proof {
+    if let Some(stone_proof) = &inputs.stark_proof {
         let proof_string = serde_json::json!({
             "proof": stone_proof
         })
@@ -1,2 +1 @@
 mod hints;
-mod types;
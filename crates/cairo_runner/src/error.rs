@@ -51,4 +51,8 @@ pub enum Error {
     IlegalInputValue,
     #[error("Runner Error: {0}")]
     Runner(#[from] RunnerError),
+    #[error("Program has no output builtin segment")]
+    MissingOutputSegment,
+    #[error("Invalid program output: {0}")]
+    InvalidOutput(String),
 }
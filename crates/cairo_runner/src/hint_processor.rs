@@ -1,6 +1,7 @@
 use std::{any::Any, collections::HashMap};
 
-use crate::recursive_epoch::{RecursiveEpochUpdateCairo, HINT_WRITE_EXPECTED_PROOF_OUTPUT};
+use crate::recursive_epoch::HINT_WRITE_EXPECTED_PROOF_OUTPUT;
+use cairo_types::RecursiveEpochUpdateCairo;
 use cairo_vm::{
     hint_processor::{
         builtin_hint_processor::builtin_hint_processor_definition::{
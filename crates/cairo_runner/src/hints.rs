@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use cairo_types::{CairoType, Uint256};
 use cairo_vm::{
     hint_processor::builtin_hint_processor::{
         builtin_hint_processor_definition::HintProcessorData,
@@ -11,9 +12,6 @@ use cairo_vm::{
     vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
     Felt252,
 };
-use garaga_zero::types::CairoType;
-
-use crate::types::Uint256;
 
 pub const HINT_CHECK_FORK_VERSION: &str = r#"check_fork_version()"#;
 
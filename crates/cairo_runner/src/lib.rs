@@ -4,7 +4,8 @@ pub mod recursive_epoch;
 use bincode::enc::write::Writer;
 
 pub mod hint_processor;
-pub mod types;
+pub mod output;
+use cairo_types::RecursiveEpochUpdateCairo;
 use cairo_vm::{
     cairo_run::{
         self, cairo_run_program_with_initial_scope, write_encoded_memory, write_encoded_trace,
@@ -14,7 +15,6 @@ use cairo_vm::{
 };
 use error::Error;
 use hint_processor::CustomHintProcessor;
-use recursive_epoch::RecursiveEpochUpdateCairo;
 use std::io;
 use std::{io::Write, path::Path};
 
@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+
+use cairo_vm::{
+    types::{builtin_name::BuiltinName, relocatable::MaybeRelocatable, relocatable::Relocatable},
+    vm::runners::{builtin_runner::BuiltinRunner, cairo_pie::CairoPie, cairo_runner::CairoRunner},
+    Felt252,
+};
+
+use crate::error::Error;
+
+/// Reads the output builtin segment of a Cairo PIE
+///
+/// # Arguments
+/// * `pie` - The PIE of a finished run
+///
+/// # Returns
+/// * `Result<Vec<Felt252>, Error>` - The values written to the output builtin, in order
+pub fn output_from_pie(pie: &CairoPie) -> Result<Vec<Felt252>, Error> {
+    let segment = pie
+        .metadata
+        .builtin_segments
+        .get(&BuiltinName::output)
+        .ok_or(Error::MissingOutputSegment)?;
+    let index = usize::try_from(segment.index).map_err(|_| Error::MissingOutputSegment)?;
+
+    let mut cells = pie
+        .memory
+        .0
+        .iter()
+        .filter(|((segment_index, _), _)| *segment_index == index)
+        .map(|((_, offset), value)| match value {
+            MaybeRelocatable::Int(value) => Ok((*offset, *value)),
+            MaybeRelocatable::RelocatableValue(_) => Err(Error::InvalidOutput(format!(
+                "Output cell {} is a pointer",
+                offset
+            ))),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    cells.sort_by_key(|(offset, _)| *offset);
+
+    // The output has to be a contiguous segment of the size recorded in the metadata
+    if cells.len() != segment.size || cells.iter().enumerate().any(|(i, (o, _))| i != *o) {
+        return Err(Error::InvalidOutput(format!(
+            "Expected {} contiguous output cells, got {}",
+            segment.size,
+            cells.len()
+        )));
+    }
+
+    Ok(cells.into_iter().map(|(_, value)| value).collect())
+}
+
+/// Reads the output builtin segment of a finished runner
+///
+/// # Arguments
+/// * `runner` - The runner after the program has been executed
+///
+/// # Returns
+/// * `Result<Vec<Felt252>, Error>` - The values written to the output builtin, in order
+pub fn output_from_runner(runner: &CairoRunner) -> Result<Vec<Felt252>, Error> {
+    let base = runner
+        .vm
+        .get_builtin_runners()
+        .iter()
+        .find_map(|builtin| match builtin {
+            BuiltinRunner::Output(output) => Some(output.base()),
+            _ => None,
+        })
+        .ok_or(Error::MissingOutputSegment)?;
+
+    let size = runner
+        .vm
+        .get_segment_used_size(base)
+        .ok_or(Error::MissingOutputSegment)?;
+    let values = runner
+        .vm
+        .get_integer_range(Relocatable::from((base as isize, 0)), size)?;
+
+    Ok(values.into_iter().map(Cow::into_owned).collect())
+}
@@ -0,0 +1,79 @@
+use cairo_vm_base::vm::cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{check_layout, primitives::UInt384, CairoType};
+
+/// Garaga `G1Point`, affine coordinates over the BLS12-381 base field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct G1PointCairo {
+    pub x: UInt384,
+    pub y: UInt384,
+}
+
+impl CairoType for G1PointCairo {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        Ok(Self {
+            x: UInt384::from_memory(vm, address)?,
+            y: UInt384::from_memory(vm, (address + 4)?)?,
+        })
+    }
+
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let mut current_ptr = address;
+
+        current_ptr = self.x.to_memory(vm, current_ptr)?;
+        current_ptr = self.y.to_memory(vm, current_ptr)?;
+
+        check_layout("G1PointCairo", address, current_ptr, Self::n_fields())
+    }
+
+    fn n_fields() -> usize {
+        UInt384::n_fields() * 2
+    }
+}
+
+/// Garaga `G2Point`, affine coordinates over the quadratic extension field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct G2PointCairo {
+    pub x0: UInt384,
+    pub x1: UInt384,
+    pub y0: UInt384,
+    pub y1: UInt384,
+}
+
+impl CairoType for G2PointCairo {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        Ok(Self {
+            x0: UInt384::from_memory(vm, address)?,
+            x1: UInt384::from_memory(vm, (address + 4)?)?,
+            y0: UInt384::from_memory(vm, (address + 8)?)?,
+            y1: UInt384::from_memory(vm, (address + 12)?)?,
+        })
+    }
+
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let mut current_ptr = address;
+
+        current_ptr = self.x0.to_memory(vm, current_ptr)?;
+        current_ptr = self.x1.to_memory(vm, current_ptr)?;
+        current_ptr = self.y0.to_memory(vm, current_ptr)?;
+        current_ptr = self.y1.to_memory(vm, current_ptr)?;
+
+        check_layout("G2PointCairo", address, current_ptr, Self::n_fields())
+    }
+
+    fn n_fields() -> usize {
+        UInt384::n_fields() * 4
+    }
+}
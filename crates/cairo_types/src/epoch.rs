@@ -0,0 +1,317 @@
+use cairo_vm_base::vm::cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    bls::{G1PointCairo, G2PointCairo},
+    check_layout, is_set,
+    primitives::{Felt, UInt384, Uint256, Uint256Bits32},
+    read_array, write_array, CairoType,
+};
+
+/// Length of the execution payload path in the beacon block body, see `verify_epoch.cairo`
+pub const EXECUTION_HEADER_PATH_LEN: usize = 4;
+/// Maximum number of execution payload fields read by `SSZ.hash_execution_payload_header_root`
+pub const MAX_PAYLOAD_FIELDS: usize = 17;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecursiveEpochUpdateCairo {
+    pub inputs: RecursiveEpochInputsCairo,
+    pub outputs: RecursiveEpochOutputsCairo,
+}
+
+/// Mirrors `CircuitOutput`, the 13 felts written by `write_circuit_output`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecursiveEpochOutputsCairo {
+    pub beacon_header_root: Uint256,
+    pub beacon_state_root: Uint256,
+    pub beacon_height: Felt,
+    pub n_signers: Felt,
+    pub execution_header_root: Uint256,
+    pub execution_header_height: Felt,
+    pub current_committee_hash: Uint256,
+    pub next_committee_hash: Uint256,
+}
+
+impl CairoType for RecursiveEpochOutputsCairo {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        Ok(Self {
+            beacon_header_root: Uint256::from_memory(vm, address)?,
+            beacon_state_root: Uint256::from_memory(vm, (address + 2)?)?,
+            beacon_height: Felt::from_memory(vm, (address + 4)?)?,
+            n_signers: Felt::from_memory(vm, (address + 5)?)?,
+            execution_header_root: Uint256::from_memory(vm, (address + 6)?)?,
+            execution_header_height: Felt::from_memory(vm, (address + 8)?)?,
+            current_committee_hash: Uint256::from_memory(vm, (address + 9)?)?,
+            next_committee_hash: Uint256::from_memory(vm, (address + 11)?)?,
+        })
+    }
+
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let mut current_ptr = address;
+
+        current_ptr = self.beacon_header_root.to_memory(vm, current_ptr)?;
+        current_ptr = self.beacon_state_root.to_memory(vm, current_ptr)?;
+        current_ptr = self.beacon_height.to_memory(vm, current_ptr)?;
+        current_ptr = self.n_signers.to_memory(vm, current_ptr)?;
+        current_ptr = self.execution_header_root.to_memory(vm, current_ptr)?;
+        current_ptr = self.execution_header_height.to_memory(vm, current_ptr)?;
+        current_ptr = self.current_committee_hash.to_memory(vm, current_ptr)?;
+        current_ptr = self.next_committee_hash.to_memory(vm, current_ptr)?;
+
+        check_layout(
+            "RecursiveEpochOutputsCairo",
+            address,
+            current_ptr,
+            Self::n_fields(),
+        )
+    }
+
+    fn n_fields() -> usize {
+        Uint256::n_fields() * 5 + Felt::n_fields() * 3
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecursiveEpochInputsCairo {
+    pub epoch_update: EpochUpdateCairo,
+    pub sync_committee_update: Option<SyncCommitteeDataCairo>,
+    /// The stark proof of the previous epoch update
+    #[serde(alias = "stone_proof")]
+    pub stark_proof: Option<Value>,
+    pub stark_proof_output: Option<RecursiveEpochOutputsCairo>,
+}
+
+/// Mirrors `EpochUpdate`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EpochUpdateCairo {
+    #[serde(alias = "sig_point")]
+    pub signature_point: G2PointCairo,
+    pub header: BeaconHeaderCairo,
+    pub signer_data: SignerDataCairo,
+    pub execution_header_proof: ExecutionHeaderProofCairo,
+}
+
+impl CairoType for EpochUpdateCairo {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let header_ptr = (address + G2PointCairo::n_fields())?;
+        let signer_data_ptr = (header_ptr + BeaconHeaderCairo::n_fields())?;
+        let execution_header_proof_ptr = (signer_data_ptr + SignerDataCairo::n_fields())?;
+
+        Ok(Self {
+            signature_point: G2PointCairo::from_memory(vm, address)?,
+            header: BeaconHeaderCairo::from_memory(vm, header_ptr)?,
+            signer_data: SignerDataCairo::from_memory(vm, signer_data_ptr)?,
+            execution_header_proof: ExecutionHeaderProofCairo::from_memory(
+                vm,
+                execution_header_proof_ptr,
+            )?,
+        })
+    }
+
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let mut current_ptr = address;
+
+        current_ptr = self.signature_point.to_memory(vm, current_ptr)?;
+        current_ptr = self.header.to_memory(vm, current_ptr)?;
+        current_ptr = self.signer_data.to_memory(vm, current_ptr)?;
+        current_ptr = self.execution_header_proof.to_memory(vm, current_ptr)?;
+
+        check_layout("EpochUpdateCairo", address, current_ptr, Self::n_fields())
+    }
+
+    fn n_fields() -> usize {
+        G2PointCairo::n_fields()
+            + BeaconHeaderCairo::n_fields()
+            + SignerDataCairo::n_fields()
+            + ExecutionHeaderProofCairo::n_fields()
+    }
+}
+
+/// Mirrors `BeaconHeader`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BeaconHeaderCairo {
+    pub slot: Uint256,
+    pub proposer_index: Uint256,
+    pub parent_root: Uint256,
+    pub state_root: Uint256,
+    pub body_root: Uint256,
+}
+
+impl CairoType for BeaconHeaderCairo {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        Ok(Self {
+            slot: Uint256::from_memory(vm, address)?,
+            proposer_index: Uint256::from_memory(vm, (address + 2)?)?,
+            parent_root: Uint256::from_memory(vm, (address + 4)?)?,
+            state_root: Uint256::from_memory(vm, (address + 6)?)?,
+            body_root: Uint256::from_memory(vm, (address + 8)?)?,
+        })
+    }
+
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let mut current_ptr = address;
+
+        current_ptr = self.slot.to_memory(vm, current_ptr)?;
+        current_ptr = self.proposer_index.to_memory(vm, current_ptr)?;
+        current_ptr = self.parent_root.to_memory(vm, current_ptr)?;
+        current_ptr = self.state_root.to_memory(vm, current_ptr)?;
+        current_ptr = self.body_root.to_memory(vm, current_ptr)?;
+
+        check_layout("BeaconHeaderCairo", address, current_ptr, Self::n_fields())
+    }
+
+    fn n_fields() -> usize {
+        Uint256::n_fields() * 5
+    }
+}
+
+/// Mirrors `SignerData`. `n_non_signers` is derived from the list of non-signers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignerDataCairo {
+    #[serde(alias = "aggregate_pub")]
+    pub committee_pub: G1PointCairo,
+    pub non_signers: Vec<G1PointCairo>,
+}
+
+impl CairoType for SignerDataCairo {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let non_signers_ptr = (address + G1PointCairo::n_fields())?;
+        let n_non_signers = Felt::from_memory(vm, (non_signers_ptr + 1)?)?;
+        let n_non_signers = usize::try_from(n_non_signers.0)
+            .map_err(|_| HintError::CustomHint("n_non_signers exceeds usize".to_string().into()))?;
+
+        Ok(Self {
+            committee_pub: G1PointCairo::from_memory(vm, address)?,
+            non_signers: read_array(vm, non_signers_ptr, n_non_signers)?,
+        })
+    }
+
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let mut current_ptr = address;
+
+        current_ptr = self.committee_pub.to_memory(vm, current_ptr)?;
+        current_ptr = write_array(vm, current_ptr, &self.non_signers)?;
+        current_ptr = Felt(Felt252::from(self.non_signers.len())).to_memory(vm, current_ptr)?;
+
+        check_layout("SignerDataCairo", address, current_ptr, Self::n_fields())
+    }
+
+    fn n_fields() -> usize {
+        G1PointCairo::n_fields() + 1 + Felt::n_fields()
+    }
+}
+
+/// Mirrors `ExecutionHeaderProof`
+///
+/// The path is a `felt**`, every node is written to its own segment. The payload fields are the
+/// SSZ leaves of the execution payload header, see `ExecutionPayloadHeaderCairo::to_field_roots`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionHeaderProofCairo {
+    pub root: Uint256,
+    pub path: Vec<Uint256Bits32>,
+    pub leaf: Uint256,
+    pub index: Felt,
+    #[serde(alias = "execution_payload_header")]
+    pub payload_fields: Vec<Uint256>,
+}
+
+impl CairoType for ExecutionHeaderProofCairo {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let path_ptr = (address + Uint256::n_fields())?;
+        let path_segment = vm.get_relocatable(path_ptr)?;
+        let path = (0..EXECUTION_HEADER_PATH_LEN)
+            .map(|i| {
+                let node = vm.get_relocatable((path_segment + i)?)?;
+                Uint256Bits32::from_memory(vm, node)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // The number of fields depends on the fork, so fields are read until the first unset cell
+        let fields_ptr = (address + 6)?;
+        let fields_segment = vm.get_relocatable(fields_ptr)?;
+        let mut payload_fields = Vec::new();
+        for i in 0..MAX_PAYLOAD_FIELDS {
+            let field_ptr = (fields_segment + i * Uint256::n_fields())?;
+            if !is_set(vm, field_ptr) {
+                break;
+            }
+            payload_fields.push(Uint256::from_memory(vm, field_ptr)?);
+        }
+
+        Ok(Self {
+            root: Uint256::from_memory(vm, address)?,
+            path,
+            leaf: Uint256::from_memory(vm, (address + 3)?)?,
+            index: Felt::from_memory(vm, (address + 5)?)?,
+            payload_fields,
+        })
+    }
+
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let mut current_ptr = address;
+
+        current_ptr = self.root.to_memory(vm, current_ptr)?;
+
+        // Create the path segment, holding a pointer to each node
+        let path_segment = vm.add_memory_segment();
+        vm.insert_value(current_ptr, path_segment)?;
+        current_ptr = (current_ptr + 1)?;
+
+        let mut path_ptr = path_segment;
+        for node in &self.path {
+            let node_segment = vm.add_memory_segment();
+            node.to_memory(vm, node_segment)?;
+            vm.insert_value(path_ptr, node_segment)?;
+            path_ptr = (path_ptr + 1)?;
+        }
+
+        current_ptr = self.leaf.to_memory(vm, current_ptr)?;
+        current_ptr = self.index.to_memory(vm, current_ptr)?;
+        current_ptr = write_array(vm, current_ptr, &self.payload_fields)?;
+
+        check_layout(
+            "ExecutionHeaderProofCairo",
+            address,
+            current_ptr,
+            Self::n_fields(),
+        )
+    }
+
+    fn n_fields() -> usize {
+        Uint256::n_fields() + 1 + Uint256::n_fields() + Felt::n_fields() + 1
+    }
+}
+
+/// Inputs of the committee update, written to separate hint variables of `recursive_update.cairo`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncCommitteeDataCairo {
+    pub beacon_slot: Felt,
+    pub next_sync_committee_branch: Vec<Uint256Bits32>,
+    pub next_aggregate_sync_committee: UInt384,
+    pub committee_keys_root: Uint256Bits32,
+}
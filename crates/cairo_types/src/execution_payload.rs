@@ -0,0 +1,139 @@
+use beacon_types::TreeHash;
+use beacon_types::{ExecutionPayloadHeader, MainnetEthSpec};
+use sha2::{Digest, Sha256};
+
+use crate::primitives::Uint256;
+
+/// Number of payload header fields `SSZ.hash_execution_payload_header_root` expects, from Deneb
+pub const CAIRO_PAYLOAD_FIELDS: usize = 17;
+/// Number of leaves `SSZ.hash_execution_payload_header_root` merkleizes
+pub const CAIRO_PAYLOAD_LEAVES: usize = 32;
+
+pub struct ExecutionPayloadHeaderCairo(pub ExecutionPayloadHeader<MainnetEthSpec>);
+
+impl ExecutionPayloadHeaderCairo {
+    /// Returns the SSZ leaves of the payload header fields, as merkleized by
+    /// `SSZ.hash_execution_payload_header_root`
+    ///
+    /// Basic values are packed into a chunk the way SSZ does: bytes are right-padded, integers
+    /// are little-endian.
+    pub fn to_field_roots(&self) -> Vec<Uint256> {
+        fn to_uint256<T: AsRef<[u8]>>(bytes: T) -> Uint256 {
+            let mut padded = [0u8; 32];
+            let bytes = bytes.as_ref();
+            padded[..bytes.len()].copy_from_slice(bytes);
+            Uint256::from_bytes_be(&padded)
+        }
+
+        fn u64_to_uint256(value: u64) -> Uint256 {
+            to_uint256(value.to_le_bytes())
+        }
+
+        macro_rules! extract_common_fields {
+            ($h:expr) => {
+                vec![
+                    to_uint256($h.parent_hash.0.as_slice()),
+                    to_uint256($h.fee_recipient.0.as_slice()),
+                    to_uint256($h.state_root.0.as_slice()),
+                    to_uint256($h.receipts_root.0.as_slice()),
+                    to_uint256($h.logs_bloom.tree_hash_root().as_slice()),
+                    to_uint256($h.prev_randao.0.as_slice()),
+                    u64_to_uint256($h.block_number),
+                    u64_to_uint256($h.gas_limit),
+                    u64_to_uint256($h.gas_used),
+                    u64_to_uint256($h.timestamp),
+                    to_uint256($h.extra_data.tree_hash_root().as_slice()),
+                    to_uint256($h.base_fee_per_gas.tree_hash_root().as_slice()),
+                    to_uint256($h.block_hash.0.as_slice()),
+                    to_uint256($h.transactions_root.as_slice()),
+                ]
+            };
+        }
+
+        match &self.0 {
+            ExecutionPayloadHeader::Bellatrix(h) => extract_common_fields!(h),
+            ExecutionPayloadHeader::Capella(h) => {
+                let mut roots = extract_common_fields!(h);
+                roots.push(to_uint256(h.withdrawals_root.as_slice()));
+                roots
+            }
+            ExecutionPayloadHeader::Deneb(h) => {
+                let mut roots = extract_common_fields!(h);
+                roots.push(to_uint256(h.withdrawals_root.as_slice()));
+                roots.push(u64_to_uint256(h.blob_gas_used));
+                roots.push(u64_to_uint256(h.excess_blob_gas));
+                roots
+            }
+            // The Electra and Fulu execution payloads are the same as Deneb
+            ExecutionPayloadHeader::Electra(h) => {
+                let mut roots = extract_common_fields!(h);
+                roots.push(to_uint256(h.withdrawals_root.as_slice()));
+                roots.push(u64_to_uint256(h.blob_gas_used));
+                roots.push(u64_to_uint256(h.excess_blob_gas));
+                roots
+            }
+            ExecutionPayloadHeader::Fulu(h) => {
+                let mut roots = extract_common_fields!(h);
+                roots.push(to_uint256(h.withdrawals_root.as_slice()));
+                roots.push(u64_to_uint256(h.blob_gas_used));
+                roots.push(u64_to_uint256(h.excess_blob_gas));
+                roots
+            }
+        }
+    }
+
+    /// Computes the SSZ root of the payload header from the field roots
+    ///
+    /// The leaves are padded with zero chunks to the next power of two, so the result equals
+    /// `ExecutionPayloadHeader::tree_hash_root()` for every fork if the field roots are correct.
+    ///
+    /// # Returns
+    /// * `[u8; 32]` - The SSZ root of the field roots
+    pub fn ssz_root(&self) -> [u8; 32] {
+        let leaves = self.leaves();
+        let width = leaves.len().next_power_of_two();
+        merkleize(leaves, width)
+    }
+
+    /// Computes the payload header root the way `SSZ.hash_execution_payload_header_root` does
+    ///
+    /// The Cairo program expects the 17 fields of the Deneb layout and always pads them to 32
+    /// leaves, which only agrees with SSZ for headers of that layout.
+    ///
+    /// # Returns
+    /// * `Option<[u8; 32]>` - The root of the field roots, `None` if the header does not have the
+    ///   Deneb layout
+    pub fn cairo_root(&self) -> Option<[u8; 32]> {
+        let leaves = self.leaves();
+        if leaves.len() != CAIRO_PAYLOAD_FIELDS {
+            return None;
+        }
+        Some(merkleize(leaves, CAIRO_PAYLOAD_LEAVES))
+    }
+
+    fn leaves(&self) -> Vec<[u8; 32]> {
+        self.to_field_roots()
+            .iter()
+            .map(Uint256::to_bytes_be)
+            .collect()
+    }
+}
+
+/// Computes the SHA256 merkle root of the chunks, padded with zero chunks to `width` leaves
+fn merkleize(mut layer: Vec<[u8; 32]>, width: usize) -> [u8; 32] {
+    layer.resize(width, [0u8; 32]);
+
+    while layer.len() > 1 {
+        layer = layer
+            .chunks(2)
+            .map(|pair| {
+                let mut hasher = Sha256::new();
+                hasher.update(pair[0]);
+                hasher.update(pair[1]);
+                hasher.finalize().into()
+            })
+            .collect();
+    }
+
+    layer[0]
+}
@@ -0,0 +1,96 @@
+//! Cairo-facing types of the Bankai programs
+//!
+//! Every type mirrors a struct of `cairo/src/types.cairo` (or a hint input of
+//! `recursive_update.cairo`) and can be written to and read from the VM memory with the same
+//! layout. The types are shared by the runner and the hint processors, so there is a single
+//! definition of how inputs are encoded.
+
+pub mod bls;
+pub mod epoch;
+pub mod execution_payload;
+pub mod primitives;
+
+use cairo_vm_base::vm::cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+
+pub use bls::{G1PointCairo, G2PointCairo};
+pub use epoch::{
+    BeaconHeaderCairo, EpochUpdateCairo, ExecutionHeaderProofCairo, RecursiveEpochInputsCairo,
+    RecursiveEpochOutputsCairo, RecursiveEpochUpdateCairo, SignerDataCairo, SyncCommitteeDataCairo,
+};
+pub use execution_payload::ExecutionPayloadHeaderCairo;
+pub use primitives::{Felt, UInt384, Uint256, Uint256Bits32};
+
+/// A type with a fixed memory layout in the Cairo VM
+pub trait CairoType: Sized {
+    /// Reads the value from memory, starting at `address`
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError>;
+
+    /// Writes the value to memory, starting at `address`
+    ///
+    /// # Returns
+    /// * `Result<Relocatable, HintError>` - The address after the last written field
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError>;
+
+    /// Returns the number of felts the type occupies, i.e. the `SIZE` of the Cairo struct
+    fn n_fields() -> usize;
+}
+
+/// Ensures a write ended exactly at the end of the struct
+pub(crate) fn check_layout(
+    name: &str,
+    address: Relocatable,
+    current_ptr: Relocatable,
+    n_fields: usize,
+) -> Result<Relocatable, HintError> {
+    let expected_ptr = (address + n_fields)?;
+    if current_ptr != expected_ptr {
+        return Err(HintError::CustomHint(
+            format!(
+                "Memory layout mismatch for {name}: expected pointer at {expected_ptr}, but got {current_ptr}"
+            )
+            .into(),
+        ));
+    }
+    Ok(current_ptr)
+}
+
+/// Writes a list of values to a new segment and stores the segment pointer at `address`
+pub(crate) fn write_array<T: CairoType>(
+    vm: &mut VirtualMachine,
+    address: Relocatable,
+    values: &[T],
+) -> Result<Relocatable, HintError> {
+    let segment = vm.add_memory_segment();
+    vm.insert_value(address, segment)?;
+
+    let mut segment_ptr = segment;
+    for value in values {
+        segment_ptr = value.to_memory(vm, segment_ptr)?;
+    }
+
+    Ok((address + 1)?)
+}
+
+/// Reads `len` values from the segment the pointer at `address` points to
+pub(crate) fn read_array<T: CairoType>(
+    vm: &VirtualMachine,
+    address: Relocatable,
+    len: usize,
+) -> Result<Vec<T>, HintError> {
+    let segment = vm.get_relocatable(address)?;
+    (0..len)
+        .map(|i| T::from_memory(vm, (segment + i * T::n_fields())?))
+        .collect()
+}
+
+/// Returns whether a memory cell has been written
+pub(crate) fn is_set(vm: &VirtualMachine, address: Relocatable) -> bool {
+    vm.get_maybe(&address).is_some()
+}
@@ -0,0 +1,213 @@
+use cairo_vm_base::vm::cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+
+use crate::CairoType;
+
+/// Implements the hex string serde representation of a `BigUint` newtype
+macro_rules! hex_serde {
+    ($name:ident) => {
+        impl TryFrom<String> for $name {
+            type Error = String;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                let hex_str = value.strip_prefix("0x").unwrap_or(&value);
+                BigUint::parse_bytes(hex_str.as_bytes(), 16)
+                    .map($name)
+                    .ok_or_else(|| format!("Invalid hex string: {}", value))
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                format!("0x{}", value.0.to_str_radix(16))
+            }
+        }
+
+        impl $name {
+            /// Creates the value from big-endian bytes
+            pub fn from_bytes_be(bytes: &[u8]) -> Self {
+                Self(BigUint::from_bytes_be(bytes))
+            }
+        }
+    };
+}
+
+/// Reads `n` limbs of `limb_size` bits, least significant limb first
+fn read_limbs(
+    vm: &VirtualMachine,
+    address: Relocatable,
+    n: usize,
+    limb_size: usize,
+) -> Result<BigUint, HintError> {
+    let mut value = BigUint::from(0u32);
+    for i in (0..n).rev() {
+        let limb = BigUint::from_bytes_be(&vm.get_integer((address + i)?)?.to_bytes_be());
+        value = (value << limb_size) | limb;
+    }
+    Ok(value)
+}
+
+/// Splits a value into `n` limbs of `limb_size` bits, least significant limb first
+fn to_limbs(value: &BigUint, n: usize, limb_size: usize) -> Vec<Felt252> {
+    let limb_mask = (BigUint::from(1u32) << limb_size) - BigUint::from(1u32);
+    (0..n)
+        .map(|i| {
+            let limb = (value >> (i * limb_size)) & &limb_mask;
+            Felt252::from_bytes_be_slice(&limb.to_bytes_be())
+        })
+        .collect()
+}
+
+/// Cairo `Uint256`, two 128 bit limbs `low` and `high`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Uint256(pub BigUint);
+
+hex_serde!(Uint256);
+
+impl Uint256 {
+    pub fn to_limbs(&self) -> [Felt252; 2] {
+        to_limbs(&self.0, 2, 128).try_into().unwrap()
+    }
+
+    /// Returns the value as 32 big-endian bytes, truncated to the lower 256 bits
+    pub fn to_bytes_be(&self) -> [u8; 32] {
+        let bytes = self.0.to_bytes_be();
+        let len = bytes.len().min(32);
+        let mut padded = [0u8; 32];
+        padded[32 - len..].copy_from_slice(&bytes[bytes.len() - len..]);
+        padded
+    }
+}
+
+impl CairoType for Uint256 {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        Ok(Self(read_limbs(vm, address, 2, 128)?))
+    }
+
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let limbs = self.to_limbs();
+        vm.insert_value((address + 0)?, limbs[0])?;
+        vm.insert_value((address + 1)?, limbs[1])?;
+        Ok((address + 2)?)
+    }
+
+    fn n_fields() -> usize {
+        2
+    }
+}
+
+/// A 256 bit value as eight 32 bit limbs, most significant limb first, as used by the SHA256
+/// builtins
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Uint256Bits32(pub BigUint);
+
+hex_serde!(Uint256Bits32);
+
+impl Uint256Bits32 {
+    pub fn to_limbs(&self) -> [Felt252; 8] {
+        let mut limbs = to_limbs(&self.0, 8, 32);
+        limbs.reverse();
+        limbs.try_into().unwrap()
+    }
+}
+
+impl CairoType for Uint256Bits32 {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let mut value = BigUint::from(0u32);
+        for i in 0..8 {
+            let limb = BigUint::from_bytes_be(&vm.get_integer((address + i)?)?.to_bytes_be());
+            value = (value << 32) | limb;
+        }
+        Ok(Self(value))
+    }
+
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        for (i, limb) in self.to_limbs().iter().enumerate() {
+            vm.insert_value((address + i)?, *limb)?;
+        }
+        Ok((address + 8)?)
+    }
+
+    fn n_fields() -> usize {
+        8
+    }
+}
+
+/// Garaga `UInt384`, four 96 bit limbs `d0` to `d3`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct UInt384(pub BigUint);
+
+hex_serde!(UInt384);
+
+impl UInt384 {
+    pub fn to_limbs(&self) -> [Felt252; 4] {
+        to_limbs(&self.0, 4, 96).try_into().unwrap()
+    }
+}
+
+impl CairoType for UInt384 {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        Ok(Self(read_limbs(vm, address, 4, 96)?))
+    }
+
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        for (i, limb) in self.to_limbs().iter().enumerate() {
+            vm.insert_value((address + i)?, *limb)?;
+        }
+        Ok((address + 4)?)
+    }
+
+    fn n_fields() -> usize {
+        4
+    }
+}
+
+/// A single field element
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Felt(pub Felt252);
+
+impl From<u64> for Felt {
+    fn from(value: u64) -> Self {
+        Self(Felt252::from(value))
+    }
+}
+
+impl CairoType for Felt {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        Ok(Self(*vm.get_integer(address)?))
+    }
+
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        vm.insert_value(address, self.0)?;
+        Ok((address + 1)?)
+    }
+
+    fn n_fields() -> usize {
+        1
+    }
+}
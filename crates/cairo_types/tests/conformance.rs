@@ -0,0 +1,269 @@
+//! Checks that the memory layout of the Rust types matches the Cairo structs in
+//! `cairo/src/types.cairo`.
+
+use std::collections::HashMap;
+
+use cairo_types::{
+    BeaconHeaderCairo, CairoType, EpochUpdateCairo, ExecutionHeaderProofCairo, Felt, G1PointCairo,
+    G2PointCairo, RecursiveEpochOutputsCairo, SignerDataCairo, UInt384, Uint256, Uint256Bits32,
+};
+use cairo_vm_base::vm::cairo_vm::{
+    types::relocatable::Relocatable, vm::vm_core::VirtualMachine, Felt252,
+};
+use num_bigint::BigUint;
+
+const TYPES_CAIRO: &str = include_str!("../../../cairo/src/types.cairo");
+
+/// The Cairo structs of `types.cairo`, as a list of `(field, type)` per struct
+struct CairoStructs(HashMap<String, Vec<(String, String)>>);
+
+impl CairoStructs {
+    fn parse() -> Self {
+        let mut structs = HashMap::new();
+        let mut current: Option<(String, Vec<(String, String)>)> = None;
+
+        for line in TYPES_CAIRO.lines().map(str::trim) {
+            if let Some(name) = line
+                .strip_prefix("struct ")
+                .and_then(|l| l.strip_suffix('{'))
+            {
+                current = Some((name.trim().to_string(), Vec::new()));
+            } else if line == "}" {
+                let (name, fields) = current.take().expect("unbalanced struct");
+                structs.insert(name, fields);
+            } else if let Some((_, fields)) = current.as_mut() {
+                if let Some((field, ty)) = line.trim_end_matches(',').split_once(':') {
+                    fields.push((field.trim().to_string(), ty.trim().to_string()));
+                }
+            }
+        }
+
+        Self(structs)
+    }
+
+    /// Returns the `SIZE` of a Cairo type
+    fn size(&self, ty: &str) -> usize {
+        match ty {
+            "felt" => 1,
+            _ if ty.ends_with('*') => 1,
+            "Uint256" => 2,
+            "G1Point" => 8,
+            "G2Point" => 16,
+            _ => self.fields(ty).iter().map(|(_, ty)| self.size(ty)).sum(),
+        }
+    }
+
+    /// Returns the offset of a field within a Cairo struct
+    fn offset(&self, ty: &str, field: &str) -> usize {
+        let fields = self.fields(ty);
+        let index = fields
+            .iter()
+            .position(|(name, _)| name == field)
+            .unwrap_or_else(|| panic!("{ty} has no field {field}"));
+        fields[..index].iter().map(|(_, ty)| self.size(ty)).sum()
+    }
+
+    fn fields(&self, ty: &str) -> &[(String, String)] {
+        self.0
+            .get(ty)
+            .unwrap_or_else(|| panic!("unknown Cairo type {ty}"))
+    }
+}
+
+fn uint256(value: u64) -> Uint256 {
+    // Sets both limbs, so swapped limbs are caught
+    Uint256((BigUint::from(value) << 128) | BigUint::from(value + 1))
+}
+
+fn uint384(value: u64) -> UInt384 {
+    UInt384((BigUint::from(value) << 300) | BigUint::from(value))
+}
+
+fn g1(value: u64) -> G1PointCairo {
+    G1PointCairo {
+        x: uint384(value),
+        y: uint384(value + 1),
+    }
+}
+
+fn circuit_output() -> RecursiveEpochOutputsCairo {
+    RecursiveEpochOutputsCairo {
+        beacon_header_root: uint256(1),
+        beacon_state_root: uint256(3),
+        beacon_height: Felt::from(5),
+        n_signers: Felt::from(6),
+        execution_header_root: uint256(7),
+        execution_header_height: Felt::from(9),
+        current_committee_hash: uint256(10),
+        next_committee_hash: uint256(12),
+    }
+}
+
+fn epoch_update() -> EpochUpdateCairo {
+    EpochUpdateCairo {
+        signature_point: G2PointCairo {
+            x0: uint384(1),
+            x1: uint384(2),
+            y0: uint384(3),
+            y1: uint384(4),
+        },
+        header: BeaconHeaderCairo {
+            slot: uint256(5),
+            proposer_index: uint256(6),
+            parent_root: uint256(7),
+            state_root: uint256(8),
+            body_root: uint256(9),
+        },
+        signer_data: SignerDataCairo {
+            committee_pub: g1(10),
+            non_signers: vec![g1(12), g1(14), g1(16)],
+        },
+        execution_header_proof: ExecutionHeaderProofCairo {
+            root: uint256(18),
+            path: (19..23)
+                .map(|i: u64| Uint256Bits32(BigUint::from(i) << 200))
+                .collect(),
+            leaf: uint256(23),
+            index: Felt::from(9),
+            payload_fields: (24..41).map(uint256).collect(),
+        },
+    }
+}
+
+fn write<T: CairoType>(value: &T) -> (VirtualMachine, Relocatable) {
+    let mut vm = VirtualMachine::new(false, false);
+    let address = vm.add_memory_segment();
+    let end = value.to_memory(&mut vm, address).unwrap();
+    assert_eq!(end, (address + T::n_fields()).unwrap());
+    (vm, address)
+}
+
+#[test]
+fn struct_sizes_match_cairo() {
+    let structs = CairoStructs::parse();
+
+    assert_eq!(G1PointCairo::n_fields(), structs.size("G1Point"));
+    assert_eq!(G2PointCairo::n_fields(), structs.size("G2Point"));
+    assert_eq!(BeaconHeaderCairo::n_fields(), structs.size("BeaconHeader"));
+    assert_eq!(SignerDataCairo::n_fields(), structs.size("SignerData"));
+    assert_eq!(
+        ExecutionHeaderProofCairo::n_fields(),
+        structs.size("ExecutionHeaderProof")
+    );
+    assert_eq!(EpochUpdateCairo::n_fields(), structs.size("EpochUpdate"));
+    assert_eq!(
+        RecursiveEpochOutputsCairo::n_fields(),
+        structs.size("CircuitOutput")
+    );
+}
+
+#[test]
+fn circuit_output_fields_are_at_cairo_offsets() {
+    let structs = CairoStructs::parse();
+    let output = circuit_output();
+    let (vm, address) = write(&output);
+
+    let felt_at = |field: &str, limb: usize| {
+        let offset = structs.offset("CircuitOutput", field) + limb;
+        vm.get_integer((address + offset).unwrap())
+            .unwrap()
+            .into_owned()
+    };
+
+    let uint256_fields = [
+        ("beacon_header_root", &output.beacon_header_root),
+        ("beacon_state_root", &output.beacon_state_root),
+        ("execution_header_root", &output.execution_header_root),
+        ("current_committee_hash", &output.current_committee_hash),
+        ("next_committee_hash", &output.next_committee_hash),
+    ];
+    for (field, value) in uint256_fields {
+        let limbs = value.to_limbs();
+        assert_eq!(felt_at(field, 0), limbs[0], "{field}.low");
+        assert_eq!(felt_at(field, 1), limbs[1], "{field}.high");
+    }
+
+    assert_eq!(felt_at("beacon_height", 0), output.beacon_height.0);
+    assert_eq!(felt_at("n_signers", 0), output.n_signers.0);
+    assert_eq!(
+        felt_at("execution_header_height", 0),
+        output.execution_header_height.0
+    );
+}
+
+#[test]
+fn epoch_update_fields_are_at_cairo_offsets() {
+    let structs = CairoStructs::parse();
+    let update = epoch_update();
+    let (vm, address) = write(&update);
+
+    let at = |offset: usize| (address + offset).unwrap();
+
+    let header = structs.offset("EpochUpdate", "header");
+    let state_root = header + structs.offset("BeaconHeader", "state_root");
+    assert_eq!(
+        Uint256::from_memory(&vm, at(state_root)).unwrap(),
+        update.header.state_root
+    );
+
+    let signer_data = structs.offset("EpochUpdate", "signer_data");
+    let n_non_signers = signer_data + structs.offset("SignerData", "n_non_signers");
+    assert_eq!(
+        vm.get_integer(at(n_non_signers)).unwrap().into_owned(),
+        Felt252::from(update.signer_data.non_signers.len())
+    );
+
+    let proof = structs.offset("EpochUpdate", "execution_header_proof");
+    let index = proof + structs.offset("ExecutionHeaderProof", "index");
+    assert_eq!(
+        vm.get_integer(at(index)).unwrap().into_owned(),
+        update.execution_header_proof.index.0
+    );
+
+    // `path` is a `felt**`, the first node is behind two pointers
+    let path = proof + structs.offset("ExecutionHeaderProof", "path");
+    let path_segment = vm.get_relocatable(at(path)).unwrap();
+    let first_node = vm.get_relocatable(path_segment).unwrap();
+    assert_eq!(
+        Uint256Bits32::from_memory(&vm, first_node).unwrap(),
+        update.execution_header_proof.path[0]
+    );
+
+    let payload_fields = proof + structs.offset("ExecutionHeaderProof", "payload_fields");
+    let fields_segment = vm.get_relocatable(at(payload_fields)).unwrap();
+    assert_eq!(
+        Uint256::from_memory(&vm, (fields_segment + 2).unwrap()).unwrap(),
+        update.execution_header_proof.payload_fields[1]
+    );
+}
+
+#[test]
+fn memory_round_trip() {
+    let output = circuit_output();
+    let (vm, address) = write(&output);
+    assert_eq!(
+        RecursiveEpochOutputsCairo::from_memory(&vm, address).unwrap(),
+        output
+    );
+
+    let update = epoch_update();
+    let (vm, address) = write(&update);
+    assert_eq!(EpochUpdateCairo::from_memory(&vm, address).unwrap(), update);
+}
+
+#[test]
+fn serde_round_trip() {
+    let update = epoch_update();
+    let json = serde_json::to_string(&update).unwrap();
+    assert_eq!(
+        serde_json::from_str::<EpochUpdateCairo>(&json).unwrap(),
+        update
+    );
+
+    let output = circuit_output();
+    let json = serde_json::to_string(&output).unwrap();
+    assert_eq!(
+        serde_json::from_str::<RecursiveEpochOutputsCairo>(&json).unwrap(),
+        output
+    );
+}
@@ -0,0 +1,119 @@
+//! Checks that the payload header field roots merkleize to the SSZ root of the header, so byte
+//! order and padding bugs in `to_field_roots` are caught before the Cairo program runs, and that
+//! the 32 leaf padding of the Cairo program agrees with SSZ for the forks it supports.
+
+use beacon_types::{
+    Address, ExecutionBlockHash, ExecutionPayloadHeader, ExecutionPayloadHeaderBellatrix,
+    ExecutionPayloadHeaderCapella, ExecutionPayloadHeaderDeneb, ExecutionPayloadHeaderElectra,
+    FixedVector, Hash256, MainnetEthSpec, TreeHash, Uint256, VariableList,
+};
+use cairo_types::execution_payload::{ExecutionPayloadHeaderCairo, CAIRO_PAYLOAD_FIELDS};
+
+/// Fills the fields shared by all forks with distinct, non-symmetric values
+macro_rules! common_fields {
+    ($ty:ident) => {
+        $ty::<MainnetEthSpec> {
+            parent_hash: ExecutionBlockHash::from_root(Hash256::repeat_byte(0x01)),
+            fee_recipient: Address::repeat_byte(0x02),
+            state_root: Hash256::repeat_byte(0x03),
+            receipts_root: Hash256::repeat_byte(0x04),
+            logs_bloom: FixedVector::new((0..=255).collect()).unwrap(),
+            prev_randao: Hash256::repeat_byte(0x05),
+            block_number: 21_000_000,
+            gas_limit: 36_000_000,
+            gas_used: 12_345_678,
+            timestamp: 1_740_000_000,
+            extra_data: VariableList::new(b"bankai".to_vec()).unwrap(),
+            base_fee_per_gas: Uint256::from(7_000_000_007u64),
+            block_hash: ExecutionBlockHash::from_root(Hash256::repeat_byte(0x06)),
+            transactions_root: Hash256::repeat_byte(0x07),
+            ..Default::default()
+        }
+    };
+}
+
+fn headers() -> Vec<(&'static str, ExecutionPayloadHeader<MainnetEthSpec>)> {
+    vec![
+        (
+            "bellatrix",
+            ExecutionPayloadHeader::Bellatrix(common_fields!(ExecutionPayloadHeaderBellatrix)),
+        ),
+        (
+            "capella",
+            ExecutionPayloadHeader::Capella(ExecutionPayloadHeaderCapella {
+                withdrawals_root: Hash256::repeat_byte(0x08),
+                ..common_fields!(ExecutionPayloadHeaderCapella)
+            }),
+        ),
+        (
+            "deneb",
+            ExecutionPayloadHeader::Deneb(ExecutionPayloadHeaderDeneb {
+                withdrawals_root: Hash256::repeat_byte(0x08),
+                blob_gas_used: 393_216,
+                excess_blob_gas: 79_953_920,
+                ..common_fields!(ExecutionPayloadHeaderDeneb)
+            }),
+        ),
+        (
+            "electra",
+            ExecutionPayloadHeader::Electra(ExecutionPayloadHeaderElectra {
+                withdrawals_root: Hash256::repeat_byte(0x08),
+                blob_gas_used: 786_432,
+                excess_blob_gas: 104_857_600,
+                ..common_fields!(ExecutionPayloadHeaderElectra)
+            }),
+        ),
+    ]
+}
+
+#[test]
+fn field_roots_merkleize_to_header_root() {
+    for (fork, header) in headers() {
+        let expected = header.tree_hash_root();
+        let root = ExecutionPayloadHeaderCairo(header).ssz_root();
+        assert_eq!(root, expected.0, "{fork}");
+    }
+}
+
+#[test]
+fn cairo_root_matches_header_root_from_deneb() {
+    for (fork, header) in headers() {
+        let expected = header.tree_hash_root();
+        let root = ExecutionPayloadHeaderCairo(header).cairo_root();
+        match fork {
+            // The Cairo program only merkleizes the Deneb layout
+            "bellatrix" | "capella" => assert_eq!(root, None, "{fork}"),
+            _ => assert_eq!(root, Some(expected.0), "{fork}"),
+        }
+    }
+}
+
+#[test]
+fn field_roots_match_header_fields() {
+    for (fork, header) in headers() {
+        let roots = ExecutionPayloadHeaderCairo(header.clone()).to_field_roots();
+
+        let expected_len = match fork {
+            "bellatrix" => 14,
+            "capella" => 15,
+            _ => CAIRO_PAYLOAD_FIELDS,
+        };
+        assert_eq!(roots.len(), expected_len, "{fork}");
+
+        // Integers are packed little-endian, the block number is read back by the Cairo program
+        let mut block_number = [0u8; 32];
+        block_number[..8].copy_from_slice(&header.block_number().to_le_bytes());
+        assert_eq!(roots[6].to_bytes_be(), block_number, "{fork}");
+
+        // Byte vectors shorter than a chunk are right-padded
+        let mut fee_recipient = [0u8; 32];
+        fee_recipient[..20].copy_from_slice(header.fee_recipient().as_slice());
+        assert_eq!(roots[1].to_bytes_be(), fee_recipient, "{fork}");
+
+        assert_eq!(
+            roots[12].to_bytes_be(),
+            header.block_hash().into_root().0,
+            "{fork}"
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use alloy_primitives::FixedBytes;
 use alloy_rpc_types_beacon::{
     events::light_client_finality::SyncAggregate, header::HeaderResponse,
 };
@@ -8,7 +9,9 @@ use thiserror::Error;
 use tokio::time::{sleep, Duration};
 use tracing::warn;
 
-use beacon_types::{eth_spec::MainnetEthSpec, BeaconBlockBody, FullPayload};
+use beacon_types::{
+    eth_spec::MainnetEthSpec, BeaconBlockBody, BeaconState, ChainSpec, FullPayload,
+};
 
 use crate::{
     fetcher::sync_committee_input::SyncCommitteeValidatorPubs,
@@ -31,13 +34,25 @@ pub enum BeaconError {
     ParseInt(#[from] std::num::ParseIntError),
 }
 
+/// A full beacon block as returned by the `eth/v2/beacon/blocks` endpoint.
+/// Holds the header fields together with the decoded body, so a single request
+/// is enough to derive both the header and the execution payload proof.
+#[derive(Debug, Clone)]
+pub struct BeaconBlock {
+    pub slot: u64,
+    pub proposer_index: u64,
+    pub parent_root: FixedBytes<32>,
+    pub state_root: FixedBytes<32>,
+    pub body: BeaconBlockBody<MainnetEthSpec, FullPayload<MainnetEthSpec>>,
+}
+
 /// A client for interacting with the Ethereum Beacon Chain RPC endpoints.
 /// Provides methods to fetch headers, sync aggregates, and validator information.
 #[derive(Debug)]
 pub struct BeaconRpcClient {
     provider: Client,
     pub rpc_url: String,
-    _config: BankaiConfig,
+    pub config: BankaiConfig,
 }
 
 impl BeaconRpcClient {
@@ -49,7 +64,7 @@ impl BeaconRpcClient {
         Self {
             provider: reqwest::Client::new(),
             rpc_url,
-            _config: config,
+            config,
         }
     }
 
@@ -84,18 +99,66 @@ impl BeaconRpcClient {
         Ok(header)
     }
 
+    /// Fetches the raw block message for a specific slot.
+    /// Returns Error::EmptySlot if no block exists at the specified slot.
+    async fn get_block_message(&self, slot: u64) -> Result<Value, BeaconError> {
+        let json = self
+            .get_json(&format!("eth/v2/beacon/blocks/{}", slot))
+            .await?;
+
+        // Check for 404 NOT_FOUND error
+        if let Some(code) = json.get("code").and_then(|c| c.as_i64()) {
+            if code == 404 {
+                return Err(BeaconError::EmptySlot(slot));
+            }
+        }
+
+        Ok(json["data"]["message"].clone())
+    }
+
+    /// Fetches the full beacon block for a specific slot.
+    /// Returns Error::EmptySlot if no block exists at the specified slot.
+    pub async fn get_block(&self, slot: u64) -> Result<BeaconBlock, BeaconError> {
+        let message = self.get_block_message(slot).await?;
+
+        let parse_u64 = |field: &str| -> Result<u64, BeaconError> {
+            Ok(message[field]
+                .as_str()
+                .ok_or_else(|| BeaconError::InvalidResponse(format!("Missing {} field", field)))?
+                .parse()?)
+        };
+        let parse_root = |field: &str| -> Result<FixedBytes<32>, BeaconError> {
+            message[field]
+                .as_str()
+                .and_then(|root| root.parse().ok())
+                .ok_or_else(|| BeaconError::InvalidResponse(format!("Invalid {} field", field)))
+        };
+
+        Ok(BeaconBlock {
+            slot: parse_u64("slot")?,
+            proposer_index: parse_u64("proposer_index")?,
+            parent_root: parse_root("parent_root")?,
+            state_root: parse_root("state_root")?,
+            body: serde_json::from_value(message["body"].clone())?,
+        })
+    }
+
     /// Fetches the sync aggregate from the block AFTER the specified slot.
     /// Note: This intentionally fetches slot + 1 because sync aggregates reference
-    /// the previous slot's header.
-    pub async fn get_sync_aggregate(&self, mut slot: u64) -> Result<SyncAggregate, BeaconError> {
+    /// the previous slot's header. Empty slots are skipped, so the slot of the block
+    /// the aggregate was taken from is returned alongside it.
+    pub async fn get_sync_aggregate(
+        &self,
+        mut slot: u64,
+    ) -> Result<(u64, SyncAggregate), BeaconError> {
         slot += 1; // signature is in the next slot
 
         let mut attempts = 0;
 
         // Ensure the slot is not missed and increment in case it is
-        let _header = loop {
-            match self.get_header(slot).await {
-                Ok(header) => break header,
+        let message = loop {
+            match self.get_block_message(slot).await {
+                Ok(message) => break message,
                 Err(BeaconError::EmptySlot(_)) => {
                     attempts += 1;
                     if attempts >= constants::MAX_SKIPPED_SLOTS_RETRY_ATTEMPTS {
@@ -113,13 +176,8 @@ impl BeaconRpcClient {
             }
         };
 
-        let json = self
-            .get_json(&format!("eth/v2/beacon/blocks/{}", slot))
-            .await?;
-
-        let sync_aggr =
-            serde_json::from_value(json["data"]["message"]["body"]["sync_aggregate"].clone())?;
-        Ok(sync_aggr)
+        let sync_aggr = serde_json::from_value(message["body"]["sync_aggregate"].clone())?;
+        Ok((slot, sync_aggr))
     }
 
     /// Retrieves the list of validator indices that are part of the sync committee
@@ -183,12 +241,10 @@ impl BeaconRpcClient {
         &self,
         slot: u64,
     ) -> Result<BeaconBlockBody<MainnetEthSpec, FullPayload<MainnetEthSpec>>, BeaconError> {
-        let json = self
-            .get_json(&format!("eth/v2/beacon/blocks/{}", slot))
-            .await?;
+        let message = self.get_block_message(slot).await?;
 
         let block: BeaconBlockBody<MainnetEthSpec, FullPayload<MainnetEthSpec>> =
-            serde_json::from_value(json["data"]["message"]["body"].clone())?;
+            serde_json::from_value(message["body"].clone())?;
 
         Ok(block)
     }
@@ -212,6 +268,31 @@ impl BeaconRpcClient {
         Ok(pubkeys.into())
     }
 
+    /// Fetches the full beacon state at a specific slot.
+    /// The state is requested SSZ encoded, as the JSON encoding of mainnet states is several
+    /// hundred megabytes larger.
+    ///
+    /// # Arguments
+    /// * `slot` - The slot number to fetch the state for
+    pub async fn get_state(&self, slot: u64) -> Result<BeaconState<MainnetEthSpec>, BeaconError> {
+        let url = format!("{}/eth/v2/debug/beacon/states/{}", self.rpc_url, slot);
+        let response = self
+            .provider
+            .get(url)
+            .header(reqwest::header::ACCEPT, "application/octet-stream")
+            .send()
+            .await?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(BeaconError::EmptySlot(slot));
+        }
+
+        let bytes = response.error_for_status()?.bytes().await?;
+        BeaconState::from_ssz_bytes(&bytes, &ChainSpec::mainnet()).map_err(|e| {
+            BeaconError::InvalidResponse(format!("Failed to decode beacon state: {:?}", e))
+        })
+    }
+
     /// Fetches the current head slot of the beacon chain.
     ///
     /// # Returns
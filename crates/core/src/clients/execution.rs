@@ -0,0 +1,173 @@
+use alloy_consensus::{Header, ReceiptEnvelope};
+use alloy_network::eip2718::Encodable2718;
+use alloy_primitives::{Address, Bytes, B256};
+use alloy_provider::{transport::TransportError, Provider, RootProvider};
+use alloy_rpc_types::{
+    Block, BlockNumberOrTag, EIP1186AccountProofResponse, Transaction, Withdrawal,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ExecutionRpcError {
+    #[error("RPC error: {0}")]
+    Rpc(#[from] TransportError),
+    #[error("Invalid RPC url: {0}")]
+    Url(#[from] url::ParseError),
+    #[error("Block not found: {0}")]
+    BlockNotFound(u64),
+}
+
+/// A client for interacting with an Ethereum Execution Layer JSON-RPC endpoint.
+/// Provides methods to fetch the data that is verified against Bankai proven headers.
+#[derive(Debug, Clone)]
+pub struct ExecutionRpcClient {
+    provider: RootProvider,
+    pub rpc_url: String,
+}
+
+impl ExecutionRpcClient {
+    /// Creates a new ExecutionRpcClient instance.
+    ///
+    /// # Arguments
+    /// * `rpc_url` - The URL of the Execution Layer RPC endpoint
+    pub fn new(rpc_url: String) -> Result<Self, ExecutionRpcError> {
+        let provider = RootProvider::new_http(rpc_url.parse()?);
+        Ok(Self { provider, rpc_url })
+    }
+
+    /// Fetches the account and storage proofs (`eth_getProof`) of an address at a given block.
+    ///
+    /// # Arguments
+    /// * `address` - The account to fetch the proof for
+    /// * `keys` - The storage slots to include in the proof
+    /// * `block_number` - The block whose state root the proofs are generated against
+    pub async fn get_proof(
+        &self,
+        address: Address,
+        keys: Vec<B256>,
+        block_number: u64,
+    ) -> Result<EIP1186AccountProofResponse, ExecutionRpcError> {
+        let proof = self
+            .provider
+            .get_proof(address, keys)
+            .number(block_number)
+            .await?;
+
+        Ok(proof)
+    }
+
+    /// Fetches all transactions of a block in their EIP-2718 encoding, ordered by index.
+    ///
+    /// # Arguments
+    /// * `block_number` - The block to fetch the transactions of
+    pub async fn get_block_transactions(
+        &self,
+        block_number: u64,
+    ) -> Result<Vec<Bytes>, ExecutionRpcError> {
+        let block = self
+            .provider
+            .get_block_by_number(BlockNumberOrTag::Number(block_number))
+            .full()
+            .await?
+            .ok_or(ExecutionRpcError::BlockNotFound(block_number))?;
+
+        let transactions = block
+            .transactions
+            .into_transactions()
+            .map(|tx| tx.inner.encoded_2718().into())
+            .collect();
+
+        Ok(transactions)
+    }
+
+    /// Fetches all receipts of a block, ordered by transaction index.
+    ///
+    /// # Arguments
+    /// * `block_number` - The block to fetch the receipts of
+    pub async fn get_block_receipts(
+        &self,
+        block_number: u64,
+    ) -> Result<Vec<ReceiptEnvelope>, ExecutionRpcError> {
+        let receipts = self
+            .provider
+            .get_block_receipts(block_number.into())
+            .await?
+            .ok_or(ExecutionRpcError::BlockNotFound(block_number))?;
+
+        let receipts = receipts
+            .into_iter()
+            .map(|receipt| receipt.inner.into_primitives_receipt())
+            .collect();
+
+        Ok(receipts)
+    }
+
+    /// Fetches all withdrawals of a block, ordered by their position in the payload.
+    /// Blocks before Shanghai have no withdrawals and return an empty list.
+    ///
+    /// # Arguments
+    /// * `block_number` - The block to fetch the withdrawals of
+    pub async fn get_block_withdrawals(
+        &self,
+        block_number: u64,
+    ) -> Result<Vec<Withdrawal>, ExecutionRpcError> {
+        let block = self
+            .provider
+            .get_block_by_number(BlockNumberOrTag::Number(block_number))
+            .await?
+            .ok_or(ExecutionRpcError::BlockNotFound(block_number))?;
+
+        Ok(block
+            .withdrawals
+            .map(|withdrawals| withdrawals.to_vec())
+            .unwrap_or_default())
+    }
+
+    /// Fetches the consensus header of a block.
+    ///
+    /// # Arguments
+    /// * `block_number` - The block to fetch the header of
+    pub async fn get_header(&self, block_number: u64) -> Result<Header, ExecutionRpcError> {
+        let block = self
+            .provider
+            .get_block_by_number(BlockNumberOrTag::Number(block_number))
+            .await?
+            .ok_or(ExecutionRpcError::BlockNotFound(block_number))?;
+
+        Ok(block.header.inner)
+    }
+
+    /// Fetches a block, optionally including the full transactions.
+    ///
+    /// # Arguments
+    /// * `block_number` - The block to fetch
+    /// * `full` - Whether to include full transactions instead of their hashes
+    pub async fn get_block(
+        &self,
+        block_number: u64,
+        full: bool,
+    ) -> Result<Block, ExecutionRpcError> {
+        let request = self
+            .provider
+            .get_block_by_number(BlockNumberOrTag::Number(block_number));
+        let block = if full {
+            request.full().await?
+        } else {
+            request.await?
+        };
+
+        block.ok_or(ExecutionRpcError::BlockNotFound(block_number))
+    }
+
+    /// Fetches a transaction by its hash. Returns `None` for unknown transactions.
+    ///
+    /// # Arguments
+    /// * `hash` - The hash of the transaction
+    pub async fn get_transaction_by_hash(
+        &self,
+        hash: B256,
+    ) -> Result<Option<Transaction>, ExecutionRpcError> {
+        let transaction = self.provider.get_transaction_by_hash(hash).await?;
+        Ok(transaction)
+    }
+}
@@ -2,6 +2,7 @@ use thiserror::Error;
 
 pub mod atlantic;
 pub mod beacon_chain;
+pub mod execution;
 
 #[derive(Debug, Error)]
 pub enum ClientError {
@@ -9,4 +10,6 @@ pub enum ClientError {
     Beacon(#[from] beacon_chain::BeaconError),
     #[error("Atlantic error: {0}")]
     Atlantic(#[from] atlantic::AtlanticError),
+    #[error("Execution error: {0}")]
+    Execution(#[from] execution::ExecutionRpcError),
 }
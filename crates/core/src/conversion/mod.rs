@@ -1,12 +1,13 @@
-use cairo_runner::{
-    recursive_epoch::{
-        BeaconHeaderCairo, EpochUpdateCairo, ExecutionHeaderProofCairo,
-        ExecutionPayloadHeaderCairo, RecursiveEpochInputsCairo, RecursiveEpochOutputsCairo,
-        RecursiveEpochUpdateCairo, SyncCommitteeDataCairo,
-    },
-    types::{Felt, G1PointCairo, G2PointCairo, UInt384, Uint256, Uint256Bits32},
+mod output;
+
+pub use output::{run_and_check_output, OutputError, OutputMismatch};
+
+use cairo_types::{
+    BeaconHeaderCairo, EpochUpdateCairo, ExecutionHeaderProofCairo, ExecutionPayloadHeaderCairo,
+    Felt, G1PointCairo, G2PointCairo, RecursiveEpochInputsCairo, RecursiveEpochOutputsCairo,
+    RecursiveEpochUpdateCairo, SignerDataCairo, SyncCommitteeDataCairo, UInt384, Uint256,
+    Uint256Bits32,
 };
-use cairo_vm::Felt252;
 use num_bigint::BigUint;
 
 use crate::fetcher::recursive_epoch_input::{
@@ -28,12 +29,12 @@ impl From<RecursiveEpochOutput> for RecursiveEpochOutputsCairo {
         RecursiveEpochOutputsCairo {
             beacon_header_root: Uint256(BigUint::from_bytes_be(val.beacon_header_root.as_slice())),
             beacon_state_root: Uint256(BigUint::from_bytes_be(val.beacon_state_root.as_slice())),
-            beacon_height: Felt(Felt252::from(val.beacon_height)),
-            n_signers: Felt(Felt252::from(val.n_signers)),
+            beacon_height: Felt::from(val.beacon_height),
+            n_signers: Felt::from(val.n_signers),
             execution_header_root: Uint256(BigUint::from_bytes_be(
                 val.execution_header_root.as_slice(),
             )),
-            execution_header_height: Felt(Felt252::from(val.execution_header_height)),
+            execution_header_height: Felt::from(val.execution_header_height),
             current_committee_hash: Uint256(BigUint::from_bytes_be(
                 val.current_committee_hash.as_slice(),
             )),
@@ -66,7 +67,7 @@ impl From<SyncCommitteeData> for SyncCommitteeDataCairo {
             .map(|b| Uint256Bits32(BigUint::from_bytes_be(b.as_slice())))
             .collect::<Vec<Uint256Bits32>>();
         let committee_data = SyncCommitteeDataCairo {
-            beacon_slot: Felt(Felt252::from(val.beacon_slot)),
+            beacon_slot: Felt::from(val.beacon_slot),
             next_sync_committee_branch: branch,
             next_aggregate_sync_committee: UInt384(BigUint::from_bytes_be(
                 val.next_aggregate_sync_committee.as_slice(),
@@ -102,21 +103,24 @@ impl From<EpochUpdate> for EpochUpdateCairo {
             leaf: Uint256(BigUint::from_bytes_be(
                 val.execution_header_proof.leaf.as_slice(),
             )),
-            index: Felt(Felt252::from(val.execution_header_proof.index)),
-            execution_payload_header: ExecutionPayloadHeaderCairo(
+            index: Felt::from(val.execution_header_proof.index as u64),
+            payload_fields: ExecutionPayloadHeaderCairo(
                 val.execution_header_proof.execution_payload_header,
             )
             .to_field_roots(),
         };
-        let inputs = EpochUpdateCairo {
-            header: beacon_header,
-            signature_point: val.signature_point.into(),
-            aggregate_pub: val.aggregate_pub.into(),
+        let signer_data = SignerDataCairo {
+            committee_pub: val.aggregate_pub.into(),
             non_signers: val
                 .non_signers
                 .iter()
                 .map(|n| n.clone().into())
                 .collect::<Vec<G1PointCairo>>(),
+        };
+        let inputs = EpochUpdateCairo {
+            signature_point: val.signature_point.into(),
+            header: beacon_header,
+            signer_data,
             execution_header_proof,
         };
         // let expected_outputs = ExpectedEpochUpdateCairoOutputs {
@@ -151,18 +155,36 @@ impl From<EpochUpdate> for EpochUpdateCairo {
     }
 }
 
+/// Converts a big-endian base field element of a point encoding into `UInt384` limbs
+///
+/// The three most significant bits of an encoding carry the compression, infinity and sort flags,
+/// they are not part of the coordinate.
+fn fp_to_uint384(bytes: &[u8]) -> UInt384 {
+    let mut fp = [0u8; 48];
+    fp.copy_from_slice(bytes);
+    fp[0] &= 0x1f;
+    UInt384::from_bytes_be(&fp)
+}
+
 impl From<G1Point> for G1PointCairo {
     fn from(val: G1Point) -> Self {
-        let json = serde_json::to_string(&val).unwrap();
-        let parsed: G1PointCairo = serde_json::from_str(&json).unwrap();
-        parsed
+        let uncompressed = val.0.to_uncompressed();
+        G1PointCairo {
+            x: fp_to_uint384(&uncompressed[0..48]),
+            y: fp_to_uint384(&uncompressed[48..96]),
+        }
     }
 }
 
 impl From<G2Point> for G2PointCairo {
     fn from(val: G2Point) -> Self {
-        let json = serde_json::to_string(&val).unwrap();
-        let parsed: G2PointCairo = serde_json::from_str(&json).unwrap();
-        parsed
+        // The uncompressed encoding orders the coefficients as x1, x0, y1, y0
+        let uncompressed = val.0.to_uncompressed();
+        G2PointCairo {
+            x0: fp_to_uint384(&uncompressed[48..96]),
+            x1: fp_to_uint384(&uncompressed[0..48]),
+            y0: fp_to_uint384(&uncompressed[144..192]),
+            y1: fp_to_uint384(&uncompressed[96..144]),
+        }
     }
 }
@@ -0,0 +1,278 @@
+use std::fmt;
+
+use alloy_primitives::FixedBytes;
+use cairo_runner::output::{output_from_pie, output_from_runner};
+use cairo_types::RecursiveEpochUpdateCairo;
+use cairo_vm::{
+    vm::runners::{cairo_pie::CairoPie, cairo_runner::CairoRunner},
+    Felt252,
+};
+use starknet_crypto::{poseidon_hash_many, Felt};
+use thiserror::Error;
+
+use crate::fetcher::recursive_epoch_input::{RecursiveEpochOutput, RecursiveEpochUpdate};
+use crate::utils::helpers;
+
+/// Number of tasks in the bootloader output of a recursive epoch proof
+const BOOTLOADER_N_TASKS: u64 = 1;
+
+impl RecursiveEpochOutput {
+    /// Number of felts written by `write_circuit_output`
+    pub const N_FELTS: usize = 13;
+
+    /// Decodes the output felts of the recursive epoch program
+    ///
+    /// `write_circuit_output` always writes the committee hashes as `current, next`. At a
+    /// committee transition the committee that becomes active is written as the current one and
+    /// the next committee hash is zero, as it is unknown until the next committee update.
+    ///
+    /// # Arguments
+    /// * `felts` - The 13 output felts, without the bootloader header
+    ///
+    /// # Returns
+    /// * `Result<RecursiveEpochOutput, OutputError>` - The decoded output or an error
+    pub fn from_felts(felts: &[Felt]) -> Result<Self, OutputError> {
+        if felts.len() != Self::N_FELTS {
+            return Err(OutputError::InvalidLength {
+                expected: Self::N_FELTS,
+                actual: felts.len(),
+            });
+        }
+
+        let output = RecursiveEpochOutput {
+            beacon_header_root: to_bytes32(felts[0], felts[1])?,
+            beacon_state_root: to_bytes32(felts[2], felts[3])?,
+            beacon_height: to_u64(felts[4])?,
+            n_signers: to_u64(felts[5])?,
+            execution_header_root: to_bytes32(felts[6], felts[7])?,
+            execution_header_height: to_u64(felts[8])?,
+            current_committee_hash: to_bytes32(felts[9], felts[10])?,
+            next_committee_hash: to_bytes32(felts[11], felts[12])?,
+        };
+
+        if helpers::is_committee_transition_slot(output.beacon_height)
+            && !output.next_committee_hash.is_zero()
+        {
+            return Err(OutputError::InvalidCommitteeTransition(
+                output.beacon_height,
+            ));
+        }
+
+        Ok(output)
+    }
+
+    /// Encodes the output the way `write_circuit_output` writes it
+    ///
+    /// # Returns
+    /// * `Vec<Felt>` - The 13 output felts, 32 byte values are split into `low, high` limbs
+    pub fn to_felts(&self) -> Vec<Felt> {
+        let mut felts = Vec::with_capacity(Self::N_FELTS);
+        felts.extend(to_limbs(&self.beacon_header_root));
+        felts.extend(to_limbs(&self.beacon_state_root));
+        felts.push(Felt::from(self.beacon_height));
+        felts.push(Felt::from(self.n_signers));
+        felts.extend(to_limbs(&self.execution_header_root));
+        felts.push(Felt::from(self.execution_header_height));
+        felts.extend(to_limbs(&self.current_committee_hash));
+        felts.extend(to_limbs(&self.next_committee_hash));
+        felts
+    }
+
+    /// Computes the output hash of a bootloader proof of this output
+    ///
+    /// The bootloader output is `[n_tasks, task_output_size, program_hash, ...output]`, the task
+    /// output size includes the size and program hash felts. The recursion step checks the
+    /// Poseidon hash of these felts.
+    ///
+    /// # Arguments
+    /// * `program_hash` - The hash of the recursive epoch program
+    ///
+    /// # Returns
+    /// * `Felt` - The Poseidon hash of the bootloader output
+    pub fn expected_output_hash(&self, program_hash: Felt) -> Felt {
+        let mut felts = vec![
+            Felt::from(BOOTLOADER_N_TASKS),
+            Felt::from(Self::N_FELTS as u64 + 2),
+            program_hash,
+        ];
+        felts.extend(self.to_felts());
+        poseidon_hash_many(&felts)
+    }
+}
+
+impl RecursiveEpochOutput {
+    /// Decodes the output builtin segment of a Cairo PIE of the recursive epoch program
+    ///
+    /// # Arguments
+    /// * `pie` - The PIE returned by `cairo_runner::run`
+    ///
+    /// # Returns
+    /// * `Result<RecursiveEpochOutput, OutputError>` - The output the program emitted
+    pub fn from_pie(pie: &CairoPie) -> Result<Self, OutputError> {
+        Self::from_felts(&to_felts(output_from_pie(pie)?))
+    }
+
+    /// Decodes the output builtin segment of a finished runner of the recursive epoch program
+    ///
+    /// # Arguments
+    /// * `runner` - The runner after the program has been executed
+    ///
+    /// # Returns
+    /// * `Result<RecursiveEpochOutput, OutputError>` - The output the program emitted
+    pub fn from_runner(runner: &CairoRunner) -> Result<Self, OutputError> {
+        Self::from_felts(&to_felts(output_from_runner(runner)?))
+    }
+
+    /// Compares the output field by field with an expected output
+    ///
+    /// # Arguments
+    /// * `expected` - The expected output, e.g. computed natively from the inputs
+    ///
+    /// # Returns
+    /// * `Vec<OutputMismatch>` - The fields that differ, empty if both outputs are equal
+    pub fn diff(&self, expected: &RecursiveEpochOutput) -> Vec<OutputMismatch> {
+        let mut mismatches = Vec::new();
+
+        macro_rules! compare {
+            ($($field:ident),*) => {
+                $(
+                    if self.$field != expected.$field {
+                        mismatches.push(OutputMismatch {
+                            field: stringify!($field),
+                            expected: expected.$field.to_string(),
+                            actual: self.$field.to_string(),
+                        });
+                    }
+                )*
+            };
+        }
+
+        compare!(
+            beacon_header_root,
+            beacon_state_root,
+            beacon_height,
+            n_signers,
+            execution_header_root,
+            execution_header_height,
+            current_committee_hash,
+            next_committee_hash
+        );
+
+        mismatches
+    }
+
+    /// Checks that a PIE emitted the expected output
+    ///
+    /// # Arguments
+    /// * `pie` - The PIE of the recursive epoch program
+    /// * `expected` - The natively computed output
+    ///
+    /// # Returns
+    /// * `Result<RecursiveEpochOutput, OutputError>` - The decoded output or the mismatching fields
+    pub fn check_pie(pie: &CairoPie, expected: &RecursiveEpochOutput) -> Result<Self, OutputError> {
+        let output = Self::from_pie(pie)?;
+        let mismatches = output.diff(expected);
+        if !mismatches.is_empty() {
+            return Err(OutputError::Mismatch(mismatches));
+        }
+        Ok(output)
+    }
+}
+
+/// Runs the recursive epoch program and checks its output against the natively computed outputs
+///
+/// A disagreement between the Cairo program and the native computation fails here, before the PIE
+/// is submitted for proving.
+///
+/// # Arguments
+/// * `program_path` - Path to the compiled recursive epoch program
+/// * `update` - The inputs and the natively computed outputs
+///
+/// # Returns
+/// * `Result<CairoPie, OutputError>` - The PIE of the run or an error
+pub fn run_and_check_output(
+    program_path: &str,
+    update: RecursiveEpochUpdate,
+) -> Result<CairoPie, OutputError> {
+    let expected = update.outputs.clone();
+    let pie = cairo_runner::run(program_path, RecursiveEpochUpdateCairo::from(update))?;
+    RecursiveEpochOutput::check_pie(&pie, &expected)?;
+    Ok(pie)
+}
+
+/// A field whose emitted value differs from the expected one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputMismatch {
+    pub field: &'static str,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl fmt::Display for OutputMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: expected {}, got {}",
+            self.field, self.expected, self.actual
+        )
+    }
+}
+
+/// Converts VM field elements into Starknet field elements
+fn to_felts(values: Vec<Felt252>) -> Vec<Felt> {
+    values
+        .iter()
+        .map(|value| Felt::from_bytes_be(&value.to_bytes_be()))
+        .collect()
+}
+
+/// Splits big-endian bytes into the `low, high` 128 bit limbs of a Cairo `Uint256`
+fn to_limbs(bytes: &FixedBytes<32>) -> [Felt; 2] {
+    [
+        Felt::from_bytes_be_slice(&bytes[16..]),
+        Felt::from_bytes_be_slice(&bytes[..16]),
+    ]
+}
+
+/// Joins the `low, high` 128 bit limbs of a Cairo `Uint256` into big-endian bytes
+fn to_bytes32(low: Felt, high: Felt) -> Result<FixedBytes<32>, OutputError> {
+    let low = low.to_bytes_be();
+    let high = high.to_bytes_be();
+    if low[..16].iter().chain(high[..16].iter()).any(|b| *b != 0) {
+        return Err(OutputError::LimbOverflow);
+    }
+
+    let mut bytes = [0u8; 32];
+    bytes[..16].copy_from_slice(&high[16..]);
+    bytes[16..].copy_from_slice(&low[16..]);
+    Ok(FixedBytes::from(bytes))
+}
+
+fn to_u64(felt: Felt) -> Result<u64, OutputError> {
+    u64::try_from(felt).map_err(|_| OutputError::ValueOverflow(felt))
+}
+
+/// Possible errors that can occur while decoding a program output
+#[derive(Debug, Error)]
+pub enum OutputError {
+    #[error("Expected {expected} output felts, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+    #[error("Uint256 limb exceeds 128 bits")]
+    LimbOverflow,
+    #[error("Value {0} exceeds u64")]
+    ValueOverflow(Felt),
+    #[error("Next committee hash must be zero at the committee transition slot {0}")]
+    InvalidCommitteeTransition(u64),
+    #[error("Cairo runner error: {0}")]
+    Runner(#[from] cairo_runner::error::Error),
+    #[error("Program output differs from the expected output: {}", format_mismatches(.0))]
+    Mismatch(Vec<OutputMismatch>),
+}
+
+fn format_mismatches(mismatches: &[OutputMismatch]) -> String {
+    mismatches
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,351 @@
+use alloy_primitives::FixedBytes;
+use async_trait::async_trait;
+use std::sync::{Mutex, MutexGuard};
+use uuid::Uuid;
+
+use super::{
+    check_transition, now_millis, EpochStore, EpochUpdate, EpochUpdateEvent, Proof, ProvenEpoch,
+    Status, StoreError,
+};
+use crate::fetcher::recursive_epoch_input::RecursiveEpochOutput;
+
+/// A store that keeps everything in memory, e.g. for tests
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    state: Mutex<MemoryState>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    epoch_updates: Vec<EpochUpdate>,
+    proofs: Vec<Proof>,
+    events: Vec<EpochUpdateEvent>,
+}
+
+impl MemoryState {
+    fn record_event(
+        &mut self,
+        uuid: &str,
+        from_status: Option<String>,
+        to_status: Status,
+        error_reason: Option<&str>,
+    ) {
+        self.events.push(EpochUpdateEvent {
+            id: self.events.len() as i64 + 1,
+            uuid: uuid.to_string(),
+            from_status,
+            to_status: to_status.as_str().to_string(),
+            error_reason: error_reason.map(str::to_string),
+            created_at: now_millis(),
+        });
+    }
+
+    /// Moves an epoch update to `next` if the transition is allowed, and records it as an event
+    fn transition(
+        &mut self,
+        uuid: &str,
+        next: Status,
+        error_reason: Option<&str>,
+    ) -> Result<(), StoreError> {
+        let update = self
+            .epoch_update_mut(uuid)
+            .ok_or_else(|| StoreError::EpochUpdateNotFound(uuid.to_string()))?;
+        check_transition(uuid, &update.status, next)?;
+
+        let current = std::mem::replace(&mut update.status, next.as_str().to_string());
+        if let Some(error_reason) = error_reason {
+            update.error_reason = Some(error_reason.to_string());
+        }
+        self.record_event(uuid, Some(current), next, error_reason);
+        Ok(())
+    }
+
+    fn epoch_update_mut(&mut self, uuid: &str) -> Option<&mut EpochUpdate> {
+        self.epoch_updates
+            .iter_mut()
+            .find(|update| update.uuid == uuid)
+    }
+
+    /// Returns the epoch updates ordered by descending slot, as the SQL stores do
+    fn by_slot_desc(&self) -> Vec<&EpochUpdate> {
+        let mut updates: Vec<_> = self.epoch_updates.iter().collect();
+        updates.sort_by(|a, b| b.slot_number.cmp(&a.slot_number));
+        updates
+    }
+
+    fn proof_where(&self, predicate: impl Fn(&RecursiveEpochOutput) -> bool) -> Option<Proof> {
+        self.epoch_updates
+            .iter()
+            .filter(|update| update.outputs.as_ref().is_some_and(&predicate))
+            .filter_map(|update| update.proof_id)
+            .find_map(|id| self.proofs.iter().find(|proof| proof.id == id).cloned())
+    }
+
+    /// Returns the proven epoch updates, with the update they were created from
+    fn proven_epochs(&self) -> impl Iterator<Item = (&EpochUpdate, ProvenEpoch)> {
+        self.epoch_updates
+            .iter()
+            .filter(|update| update.status == Status::Done.as_str())
+            .filter_map(|update| {
+                let proven = ProvenEpoch {
+                    uuid: update.uuid.clone(),
+                    outputs: update.outputs.clone()?,
+                    proof_id: update.proof_id?,
+                };
+                Some((update, proven))
+            })
+    }
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MemoryState> {
+        // A panic while holding the lock can't leave the state half-updated
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn update(&self, uuid: &str, f: impl FnOnce(&mut EpochUpdate)) {
+        if let Some(update) = self.state().epoch_update_mut(uuid) {
+            f(update);
+        }
+    }
+}
+
+#[async_trait]
+impl EpochStore for MemoryStore {
+    async fn create_epoch_update(
+        &self,
+        epoch_number: u64,
+        slot_number: u64,
+        outputs: RecursiveEpochOutput,
+        execution_timestamp: u64,
+    ) -> Result<String, StoreError> {
+        let uuid = Uuid::new_v4().to_string();
+
+        let mut state = self.state();
+        state.epoch_updates.push(EpochUpdate {
+            uuid: uuid.clone(),
+            epoch_number: epoch_number as i64,
+            slot_number: slot_number as i64,
+            outputs: Some(outputs),
+            atlantic_id: None,
+            proof_id: None,
+            status: Status::Fetching.as_str().to_string(),
+            error_reason: None,
+            execution_timestamp: Some(execution_timestamp as i64),
+        });
+        state.record_event(&uuid, None, Status::Fetching, None);
+
+        Ok(uuid)
+    }
+
+    async fn update_outputs(
+        &self,
+        uuid: &str,
+        outputs: &RecursiveEpochOutput,
+    ) -> Result<(), StoreError> {
+        self.update(uuid, |update| update.outputs = Some(outputs.clone()));
+        Ok(())
+    }
+
+    async fn add_atlantic_id(&self, uuid: &str, atlantic_id: &str) -> Result<(), StoreError> {
+        self.update(uuid, |update| {
+            update.atlantic_id = Some(atlantic_id.to_string())
+        });
+        Ok(())
+    }
+
+    async fn add_proof(&self, proof_json: &str) -> Result<i64, StoreError> {
+        let mut state = self.state();
+        if let Some(proof) = state.proofs.iter().find(|proof| proof.proof == proof_json) {
+            return Ok(proof.id);
+        }
+
+        let id = state.proofs.len() as i64 + 1;
+        state.proofs.push(Proof {
+            id,
+            proof: proof_json.to_string(),
+        });
+        Ok(id)
+    }
+
+    async fn update_proof_id(&self, uuid: &str, proof_id: i64) -> Result<(), StoreError> {
+        self.update(uuid, |update| update.proof_id = Some(proof_id));
+        Ok(())
+    }
+
+    async fn update_status(&self, uuid: &str, status: Status) -> Result<(), StoreError> {
+        self.state().transition(uuid, status, None)
+    }
+
+    async fn update_error(&self, uuid: &str, error_reason: &str) -> Result<(), StoreError> {
+        self.state()
+            .transition(uuid, Status::Error, Some(error_reason))
+    }
+
+    async fn get_epoch_update_events(
+        &self,
+        uuid: &str,
+    ) -> Result<Vec<EpochUpdateEvent>, StoreError> {
+        let events = self
+            .state()
+            .events
+            .iter()
+            .filter(|event| event.uuid == uuid)
+            .cloned()
+            .collect();
+        Ok(events)
+    }
+
+    async fn get_latest_epoch_update(&self) -> Result<Option<EpochUpdate>, StoreError> {
+        let state = self.state();
+        let update = state
+            .by_slot_desc()
+            .into_iter()
+            .find(|update| update.status != Status::Error.as_str())
+            .cloned();
+        Ok(update)
+    }
+
+    async fn get_latest_proven_epoch_update(&self) -> Result<Option<EpochUpdate>, StoreError> {
+        let state = self.state();
+        let update = state
+            .by_slot_desc()
+            .into_iter()
+            .find(|update| update.status == Status::Done.as_str())
+            .cloned();
+        Ok(update)
+    }
+
+    async fn get_proven_epoch_updates_without_execution_timestamp(
+        &self,
+    ) -> Result<Vec<EpochUpdate>, StoreError> {
+        let state = self.state();
+        let updates = state
+            .by_slot_desc()
+            .into_iter()
+            .filter(|update| {
+                update.status == Status::Done.as_str() && update.execution_timestamp.is_none()
+            })
+            .cloned()
+            .collect();
+        Ok(updates)
+    }
+
+    async fn get_epoch_update_by_uuid(
+        &self,
+        uuid: &str,
+    ) -> Result<Option<EpochUpdate>, StoreError> {
+        let update = self
+            .state()
+            .epoch_updates
+            .iter()
+            .find(|update| update.uuid == uuid)
+            .cloned();
+        Ok(update)
+    }
+
+    async fn get_proof(&self, proof_id: i64) -> Result<Option<Proof>, StoreError> {
+        let proof = self
+            .state()
+            .proofs
+            .iter()
+            .find(|proof| proof.id == proof_id)
+            .cloned();
+        Ok(proof)
+    }
+
+    async fn get_all_epoch_updates(&self) -> Result<Vec<EpochUpdate>, StoreError> {
+        let state = self.state();
+        Ok(state.by_slot_desc().into_iter().cloned().collect())
+    }
+
+    async fn get_proof_by_beacon_height(&self, height: u64) -> Result<Option<Proof>, StoreError> {
+        Ok(self
+            .state()
+            .proof_where(|outputs| outputs.beacon_height == height))
+    }
+
+    async fn get_proof_by_execution_height(
+        &self,
+        height: u64,
+    ) -> Result<Option<Proof>, StoreError> {
+        Ok(self
+            .state()
+            .proof_where(|outputs| outputs.execution_header_height == height))
+    }
+
+    async fn add_execution_timestamp(&self, uuid: &str, timestamp: u64) -> Result<(), StoreError> {
+        self.update(uuid, |update| {
+            update.execution_timestamp = Some(timestamp as i64)
+        });
+        Ok(())
+    }
+
+    async fn get_nearest_proven_epoch_by_execution_height(
+        &self,
+        block_number: u64,
+    ) -> Result<Option<ProvenEpoch>, StoreError> {
+        Ok(self
+            .state()
+            .proven_epochs()
+            .filter(|(_, proven)| proven.outputs.execution_header_height >= block_number)
+            .min_by_key(|(_, proven)| proven.outputs.execution_header_height)
+            .map(|(_, proven)| proven))
+    }
+
+    async fn get_nearest_proven_epoch_by_timestamp(
+        &self,
+        timestamp: u64,
+    ) -> Result<Option<ProvenEpoch>, StoreError> {
+        Ok(self
+            .state()
+            .proven_epochs()
+            .filter_map(|(update, proven)| Some((update.execution_timestamp?, proven)))
+            .filter(|(execution_timestamp, _)| *execution_timestamp >= timestamp as i64)
+            .min_by_key(|(execution_timestamp, _)| *execution_timestamp)
+            .map(|(_, proven)| proven))
+    }
+
+    async fn get_proven_epochs_in_execution_range(
+        &self,
+        start: u64,
+        end: u64,
+    ) -> Result<Vec<ProvenEpoch>, StoreError> {
+        let mut epochs: Vec<_> = self
+            .state()
+            .proven_epochs()
+            .map(|(_, proven)| proven)
+            .filter(|proven| (start..=end).contains(&proven.outputs.execution_header_height))
+            .collect();
+        epochs.sort_by_key(|proven| proven.outputs.execution_header_height);
+        Ok(epochs)
+    }
+
+    async fn get_proven_epoch_by_beacon_header_root(
+        &self,
+        root: &FixedBytes<32>,
+    ) -> Result<Option<ProvenEpoch>, StoreError> {
+        Ok(self
+            .state()
+            .proven_epochs()
+            .map(|(_, proven)| proven)
+            .find(|proven| proven.outputs.beacon_header_root == *root))
+    }
+
+    async fn get_proven_epoch_by_execution_block_hash(
+        &self,
+        block_hash: &FixedBytes<32>,
+    ) -> Result<Option<ProvenEpoch>, StoreError> {
+        Ok(self
+            .state()
+            .proven_epochs()
+            .map(|(_, proven)| proven)
+            .find(|proven| proven.outputs.execution_header_root == *block_hash))
+    }
+}
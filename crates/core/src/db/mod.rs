@@ -1,10 +1,28 @@
 use crate::fetcher::recursive_epoch_input::RecursiveEpochOutput;
+use alloy_primitives::FixedBytes;
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use sqlx::{sqlite::SqliteConnectOptions, FromRow, Pool, Sqlite};
+use sqlx::FromRow;
+use std::fmt::Debug;
 use std::str::FromStr;
-use uuid::Uuid;
-
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type)]
+use std::time::{SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+pub mod memory;
+pub mod postgres;
+pub mod proofs;
+pub mod sqlite;
+
+pub use memory::MemoryStore;
+pub use postgres::PostgresStore;
+pub use proofs::BlobStore;
+pub use sqlite::SqliteStore;
+
+/// The status of an epoch update job
+///
+/// A job moves through `fetching → trace_gen → proving → done`, and can fail into `error` from any
+/// state. The stores reject any other transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
 #[sqlx(type_name = "TEXT", rename_all = "snake_case")]
 pub enum Status {
     Fetching,
@@ -14,6 +32,79 @@ pub enum Status {
     Error,
 }
 
+impl Status {
+    /// Returns the value stored in the `status` column
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Fetching => "fetching",
+            Status::TraceGen => "trace_gen",
+            Status::Proving => "proving",
+            Status::Done => "done",
+            Status::Error => "error",
+        }
+    }
+
+    /// Returns whether a job in this state may move to `next`
+    pub fn can_transition_to(&self, next: Status) -> bool {
+        matches!(
+            (self, next),
+            (Status::Fetching, Status::TraceGen)
+                | (Status::TraceGen, Status::Proving)
+                | (Status::Proving, Status::Done)
+                | (_, Status::Error)
+        )
+    }
+}
+
+impl FromStr for Status {
+    type Err = StoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fetching" => Ok(Status::Fetching),
+            "trace_gen" => Ok(Status::TraceGen),
+            "proving" => Ok(Status::Proving),
+            "done" => Ok(Status::Done),
+            "error" => Ok(Status::Error),
+            _ => Err(StoreError::UnknownStatus(s.to_string())),
+        }
+    }
+}
+
+/// Checks that an epoch update in the stored status may move to `next`
+pub(crate) fn check_transition(uuid: &str, current: &str, next: Status) -> Result<(), StoreError> {
+    let current = current.parse::<Status>()?;
+    if !current.can_transition_to(next) {
+        return Err(StoreError::InvalidTransition {
+            uuid: uuid.to_string(),
+            from: current,
+            to: next,
+        });
+    }
+    Ok(())
+}
+
+/// Returns the current time in unix milliseconds, the timestamp of the recorded events
+pub(crate) fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+/// A status transition of an epoch update
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct EpochUpdateEvent {
+    pub id: i64,
+    pub uuid: String,
+    /// The previous status, `None` for the creation of the epoch update
+    pub from_status: Option<String>,
+    pub to_status: String,
+    pub error_reason: Option<String>,
+    /// Time of the transition in unix milliseconds
+    pub created_at: i64,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct EpochUpdate {
     pub uuid: String,
@@ -24,10 +115,11 @@ pub struct EpochUpdate {
     pub proof_id: Option<i64>,
     pub status: String,
     pub error_reason: Option<String>,
+    pub execution_timestamp: Option<i64>,
 }
 
 #[derive(Debug, FromRow)]
-struct EpochUpdateRow {
+pub(crate) struct EpochUpdateRow {
     pub uuid: String,
     pub epoch_number: i64,
     pub slot_number: i64,
@@ -36,6 +128,7 @@ struct EpochUpdateRow {
     pub proof_id: Option<i64>,
     pub status: String,
     pub error_reason: Option<String>,
+    pub execution_timestamp: Option<i64>,
 }
 
 impl From<EpochUpdateRow> for EpochUpdate {
@@ -54,224 +147,265 @@ impl From<EpochUpdateRow> for EpochUpdate {
             proof_id: row.proof_id,
             status: row.status,
             error_reason: row.error_reason,
+            execution_timestamp: row.execution_timestamp,
         }
     }
 }
 
+/// Selects the proven epoch updates, backends append their conditions to it
+pub(crate) const SELECT_PROVEN_EPOCHS: &str = "SELECT uuid, outputs, proof_id
+     FROM epoch_updates
+     WHERE status = 'done' AND proof_id IS NOT NULL AND outputs IS NOT NULL";
+
+/// A proven epoch update, i.e. one that is `done` and has a proof
+#[derive(Debug, Clone, Serialize)]
+pub struct ProvenEpoch {
+    pub uuid: String,
+    pub outputs: RecursiveEpochOutput,
+    pub proof_id: i64,
+}
+
 #[derive(Debug, FromRow)]
-pub struct Proof {
-    pub id: i64,
-    pub proof: String,
+pub(crate) struct ProvenEpochRow {
+    pub uuid: String,
+    pub outputs: String,
+    pub proof_id: i64,
 }
 
-#[derive(Debug)]
-pub struct Database {
-    pool: Pool<Sqlite>,
+impl TryFrom<ProvenEpochRow> for ProvenEpoch {
+    type Error = StoreError;
+
+    fn try_from(row: ProvenEpochRow) -> Result<Self, Self::Error> {
+        Ok(ProvenEpoch {
+            uuid: row.uuid,
+            outputs: serde_json::from_str(&row.outputs)?,
+            proof_id: row.proof_id,
+        })
+    }
 }
 
-impl Database {
-    pub async fn new(url: &str) -> Result<Self, sqlx::Error> {
-        let options = SqliteConnectOptions::from_str(url)?.create_if_missing(true);
+/// The fields of the outputs that are stored in their own indexed columns
+///
+/// Hashes are stored as lowercase `0x` prefixed hex, the way they are serialized in `outputs`.
+#[derive(Debug)]
+pub(crate) struct OutputColumns {
+    pub beacon_height: i64,
+    pub execution_height: i64,
+    pub execution_block_hash: String,
+    pub beacon_header_root: String,
+    pub current_committee_hash: String,
+    pub next_committee_hash: String,
+}
 
-        let pool = Pool::connect_with(options).await?;
-        sqlx::migrate!("../../../migrations").run(&pool).await?;
-        Ok(Self { pool })
+impl From<&RecursiveEpochOutput> for OutputColumns {
+    fn from(outputs: &RecursiveEpochOutput) -> Self {
+        OutputColumns {
+            beacon_height: outputs.beacon_height as i64,
+            execution_height: outputs.execution_header_height as i64,
+            execution_block_hash: outputs.execution_header_root.to_string(),
+            beacon_header_root: outputs.beacon_header_root.to_string(),
+            current_committee_hash: outputs.current_committee_hash.to_string(),
+            next_committee_hash: outputs.next_committee_hash.to_string(),
+        }
     }
+}
 
-    pub async fn create_epoch_update(
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct Proof {
+    pub id: i64,
+    pub proof: String,
+}
+
+/// Storage of epoch updates and their proofs
+///
+/// Implemented by the SQLite, Postgres and in-memory stores. All backends behave the same, which
+/// is checked by the conformance suite in `tests/epoch_store.rs`.
+#[async_trait]
+pub trait EpochStore: Debug + Send + Sync {
+    /// Creates a new epoch update in the `fetching` state
+    ///
+    /// # Arguments
+    /// * `epoch_number` - The epoch of the update
+    /// * `slot_number` - The slot the update was generated for
+    /// * `outputs` - The expected outputs of the update
+    /// * `execution_timestamp` - The timestamp of the proven execution payload header
+    ///
+    /// # Returns
+    /// * `Result<String, StoreError>` - The uuid of the new epoch update
+    async fn create_epoch_update(
         &self,
         epoch_number: u64,
         slot_number: u64,
         outputs: RecursiveEpochOutput,
-    ) -> Result<String, sqlx::Error> {
-        let uuid = Uuid::new_v4().to_string();
-        let outputs_json = serde_json::to_string(&outputs).unwrap();
-        let epoch_number_i64 = epoch_number as i64;
-        let slot_number_i64 = slot_number as i64;
-
-        sqlx::query!(
-            "INSERT INTO epoch_updates (uuid, epoch_number, slot_number, outputs, status) VALUES (?, ?, ?, ?, ?)",
-            uuid,
-            epoch_number_i64,
-            slot_number_i64,
-            outputs_json,
-            "fetching"
-        )
-        .execute(&self.pool)
-        .await?;
-
-        Ok(uuid)
-    }
+        execution_timestamp: u64,
+    ) -> Result<String, StoreError>;
 
-    pub async fn update_outputs(
+    async fn update_outputs(
         &self,
         uuid: &str,
         outputs: &RecursiveEpochOutput,
-    ) -> Result<(), sqlx::Error> {
-        let outputs_json = serde_json::to_string(outputs).unwrap();
+    ) -> Result<(), StoreError>;
 
-        sqlx::query!(
-            "UPDATE epoch_updates SET outputs = ? WHERE uuid = ?",
-            outputs_json,
-            uuid
-        )
-        .execute(&self.pool)
-        .await?;
+    async fn add_atlantic_id(&self, uuid: &str, atlantic_id: &str) -> Result<(), StoreError>;
 
-        Ok(())
-    }
+    /// Stores a proof, identical proofs are only stored once
+    ///
+    /// # Returns
+    /// * `Result<i64, StoreError>` - The id of the stored proof
+    async fn add_proof(&self, proof_json: &str) -> Result<i64, StoreError>;
 
-    pub async fn add_atlantic_id(&self, uuid: &str, atlantic_id: &str) -> Result<(), sqlx::Error> {
-        sqlx::query!(
-            "UPDATE epoch_updates SET atlantic_id = ? WHERE uuid = ?",
-            atlantic_id,
-            uuid
-        )
-        .execute(&self.pool)
-        .await?;
+    async fn update_proof_id(&self, uuid: &str, proof_id: i64) -> Result<(), StoreError>;
 
-        Ok(())
-    }
+    /// Moves the epoch update to `status` and records the transition
+    ///
+    /// # Returns
+    /// * `Result<(), StoreError>` - An error if the update doesn't exist or the transition isn't
+    ///   allowed from its current status
+    async fn update_status(&self, uuid: &str, status: Status) -> Result<(), StoreError>;
 
-    pub async fn add_proof(&self, proof_json: &str) -> Result<i64, sqlx::Error> {
-        let result = sqlx::query!(
-            "INSERT INTO proofs (proof) VALUES (?) RETURNING id",
-            proof_json
-        )
-        .fetch_one(&self.pool)
-        .await?;
+    /// Moves the epoch update to the `error` state and records the transition with the reason
+    async fn update_error(&self, uuid: &str, error_reason: &str) -> Result<(), StoreError>;
 
-        Ok(result.id)
-    }
+    /// Returns the status transitions of the epoch update, oldest first
+    async fn get_epoch_update_events(
+        &self,
+        uuid: &str,
+    ) -> Result<Vec<EpochUpdateEvent>, StoreError>;
 
-    pub async fn update_proof_id(&self, uuid: &str, proof_id: i64) -> Result<(), sqlx::Error> {
-        sqlx::query!(
-            "UPDATE epoch_updates SET proof_id = ? WHERE uuid = ?",
-            proof_id,
-            uuid
-        )
-        .execute(&self.pool)
-        .await?;
+    /// Returns the epoch update with the highest slot that is not in the `error` state
+    async fn get_latest_epoch_update(&self) -> Result<Option<EpochUpdate>, StoreError>;
 
-        Ok(())
-    }
+    /// Returns the epoch update with the highest slot that is `done`
+    async fn get_latest_proven_epoch_update(&self) -> Result<Option<EpochUpdate>, StoreError>;
 
-    pub async fn update_status(&self, uuid: &str, status: Status) -> Result<(), sqlx::Error> {
-        let status_str = match status {
-            Status::Fetching => "fetching",
-            Status::TraceGen => "trace_gen",
-            Status::Proving => "proving",
-            Status::Done => "done",
-            Status::Error => "error",
-        };
+    /// Returns the `done` epoch updates without an execution timestamp, ordered by descending slot
+    ///
+    /// Updates created before the timestamp was recorded have none, it is filled in at runtime from
+    /// the proven execution payload with `add_execution_timestamp`.
+    async fn get_proven_epoch_updates_without_execution_timestamp(
+        &self,
+    ) -> Result<Vec<EpochUpdate>, StoreError>;
 
-        sqlx::query!(
-            "UPDATE epoch_updates SET status = ? WHERE uuid = ?",
-            status_str,
-            uuid
-        )
-        .execute(&self.pool)
-        .await?;
+    async fn get_epoch_update_by_uuid(&self, uuid: &str)
+        -> Result<Option<EpochUpdate>, StoreError>;
 
-        Ok(())
-    }
+    async fn get_proof(&self, proof_id: i64) -> Result<Option<Proof>, StoreError>;
 
-    pub async fn update_error(&self, uuid: &str, error_reason: &str) -> Result<(), sqlx::Error> {
-        sqlx::query!(
-            "UPDATE epoch_updates SET status = 'error', error_reason = ? WHERE uuid = ?",
-            error_reason,
-            uuid
-        )
-        .execute(&self.pool)
-        .await?;
+    /// Returns all epoch updates, ordered by descending slot
+    async fn get_all_epoch_updates(&self) -> Result<Vec<EpochUpdate>, StoreError>;
 
-        Ok(())
-    }
+    /// Returns the proof of the epoch update with the given beacon height in its outputs
+    async fn get_proof_by_beacon_height(&self, height: u64) -> Result<Option<Proof>, StoreError>;
 
-    pub async fn get_latest_epoch_update(&self) -> Result<Option<EpochUpdate>, sqlx::Error> {
-        let row = sqlx::query_as::<_, EpochUpdateRow>(
-            "SELECT uuid, epoch_number, slot_number, outputs, atlantic_id, proof_id, status, error_reason 
-             FROM epoch_updates 
-             WHERE status != 'error'
-             ORDER BY slot_number DESC 
-             LIMIT 1"
-        )
-        .fetch_optional(&self.pool)
-        .await?;
+    /// Returns the proof of the epoch update with the given execution height in its outputs
+    async fn get_proof_by_execution_height(&self, height: u64)
+        -> Result<Option<Proof>, StoreError>;
 
-        Ok(row.map(Into::into))
-    }
+    /// Overwrites the timestamp of the execution header proven by the epoch update
+    async fn add_execution_timestamp(&self, uuid: &str, timestamp: u64) -> Result<(), StoreError>;
 
-    pub async fn get_epoch_update_by_uuid(
+    /// Returns the proven epoch with the lowest execution height at or after `block_number`
+    ///
+    /// The execution header of that epoch is the closest proven header a block can be proven
+    /// against, by walking the header chain back to the block.
+    async fn get_nearest_proven_epoch_by_execution_height(
         &self,
-        uuid: &str,
-    ) -> Result<Option<EpochUpdate>, sqlx::Error> {
-        let row = sqlx::query_as::<_, EpochUpdateRow>(
-            "SELECT uuid, epoch_number, slot_number, outputs, atlantic_id, proof_id, status, error_reason 
-             FROM epoch_updates 
-             WHERE uuid = ?"
-        )
-        .bind(uuid)
-        .fetch_optional(&self.pool)
-        .await?;
-
-        Ok(row.map(Into::into))
-    }
-
-    pub async fn get_proof(&self, proof_id: i64) -> Result<Option<Proof>, sqlx::Error> {
-        let proof = sqlx::query_as!(Proof, "SELECT id, proof FROM proofs WHERE id = ?", proof_id)
-            .fetch_optional(&self.pool)
-            .await?;
-
-        Ok(proof)
-    }
+        block_number: u64,
+    ) -> Result<Option<ProvenEpoch>, StoreError>;
 
-    pub async fn get_all_epoch_updates(&self) -> Result<Vec<EpochUpdate>, sqlx::Error> {
-        let rows = sqlx::query_as::<_, EpochUpdateRow>(
-            "SELECT uuid, epoch_number, slot_number, outputs, atlantic_id, proof_id, status, error_reason 
-             FROM epoch_updates 
-             ORDER BY slot_number DESC"
-        )
-        .fetch_all(&self.pool)
-        .await?;
-
-        let updates = rows.into_iter().map(EpochUpdate::from).collect();
-        Ok(updates)
-    }
+    /// Returns the proven epoch with the lowest execution timestamp at or after `timestamp`
+    ///
+    /// Epoch updates without a recorded execution timestamp are not considered.
+    async fn get_nearest_proven_epoch_by_timestamp(
+        &self,
+        timestamp: u64,
+    ) -> Result<Option<ProvenEpoch>, StoreError>;
 
-    pub async fn get_proof_by_beacon_height(
+    /// Returns the proven epochs with an execution height within `start..=end`, ordered by
+    /// ascending execution height
+    async fn get_proven_epochs_in_execution_range(
         &self,
-        height: u64,
-    ) -> Result<Option<Proof>, sqlx::Error> {
-        let height_i64 = height as i64;
-        let proof = sqlx::query_as(
-            "SELECT p.id, p.proof
-             FROM proofs p
-             JOIN epoch_updates eu ON p.id = eu.proof_id
-             WHERE json_extract(eu.outputs, '$.beacon_height') = ?",
-        )
-        .bind(height_i64)
-        .fetch_optional(&self.pool)
-        .await?;
+        start: u64,
+        end: u64,
+    ) -> Result<Vec<ProvenEpoch>, StoreError>;
 
-        Ok(proof)
-    }
+    async fn get_proven_epoch_by_beacon_header_root(
+        &self,
+        root: &FixedBytes<32>,
+    ) -> Result<Option<ProvenEpoch>, StoreError>;
 
-    pub async fn get_proof_by_execution_height(
+    async fn get_proven_epoch_by_execution_block_hash(
         &self,
-        height: u64,
-    ) -> Result<Option<Proof>, sqlx::Error> {
-        let height_i64 = height as i64;
-        let proof = sqlx::query_as(
-            "SELECT p.id, p.proof
-             FROM proofs p
-             JOIN epoch_updates eu ON p.id = eu.proof_id
-             WHERE json_extract(eu.outputs, '$.execution_header_height') = ?",
-        )
-        .bind(height_i64)
-        .fetch_optional(&self.pool)
-        .await?;
+        block_hash: &FixedBytes<32>,
+    ) -> Result<Option<ProvenEpoch>, StoreError>;
+}
 
-        Ok(proof)
+/// Connects to the store selected by the scheme of the database url
+///
+/// Both database backends refuse to start if the database has applied migrations that aren't in
+/// the repo. The later migrations add columns with plain `ALTER TABLE ADD COLUMN`, which is only
+/// correct on the schema the in-repo history produces, so an unknown history fails loudly instead
+/// of being migrated half way. A database migrated from outside the repo has to be checked against
+/// `20250301000000_init` and its stale `_sqlx_migrations` rows removed once.
+///
+/// # Arguments
+/// * `url` - A `sqlite:`, `postgres://` or `postgresql://` url, or `memory` for an in-memory store
+/// * `proof_blob_dir` - Directory large proofs are written to, ignored by the in-memory store
+///
+/// # Returns
+/// * `Result<Box<dyn EpochStore>, StoreError>` - The connected store, with migrations applied
+pub async fn connect(
+    url: &str,
+    proof_blob_dir: Option<&str>,
+) -> Result<Box<dyn EpochStore>, StoreError> {
+    let blob_store = proof_blob_dir.map(|dir| BlobStore::new(dir, proofs::DEFAULT_BLOB_THRESHOLD));
+    if url.starts_with("sqlite:") {
+        let store = SqliteStore::new(url).await?;
+        Ok(Box::new(match blob_store {
+            Some(blob_store) => store.with_blob_store(blob_store),
+            None => store,
+        }))
+    } else if url.starts_with("postgres://") || url.starts_with("postgresql://") {
+        let store = PostgresStore::new(url).await?;
+        Ok(Box::new(match blob_store {
+            Some(blob_store) => store.with_blob_store(blob_store),
+            None => store,
+        }))
+    } else if url == "memory" {
+        Ok(Box::new(MemoryStore::new()))
+    } else {
+        Err(StoreError::UnsupportedUrl(url.to_string()))
     }
 }
+
+#[derive(Debug, Error)]
+pub enum StoreError {
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("Migration error: {0}")]
+    Migration(#[from] sqlx::migrate::MigrateError),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("Unsupported database url: {0}")]
+    UnsupportedUrl(String),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Proof is stored in the blob store at {0}, but no blob store is configured")]
+    MissingBlobStore(String),
+    #[error("Proof {id} hashes to {actual}, but its content is stored as {expected}")]
+    ProofHashMismatch {
+        id: i64,
+        expected: String,
+        actual: String,
+    },
+    #[error("Epoch update {0} not found")]
+    EpochUpdateNotFound(String),
+    #[error("Unknown status: {0}")]
+    UnknownStatus(String),
+    #[error("Epoch update {uuid} can't move from {from:?} to {to:?}")]
+    InvalidTransition {
+        uuid: String,
+        from: Status,
+        to: Status,
+    },
+}
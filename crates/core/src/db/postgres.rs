@@ -0,0 +1,463 @@
+use alloy_primitives::FixedBytes;
+use async_trait::async_trait;
+use sqlx::{postgres::PgPoolOptions, Pool, Postgres};
+use uuid::Uuid;
+
+use super::proofs::{resolve_optional, BlobStore, EncodedProof, ProofRow, SELECT_PROOFS};
+use super::{check_transition, now_millis, EpochUpdateEvent};
+use super::{
+    EpochStore, EpochUpdate, EpochUpdateRow, OutputColumns, Proof, ProvenEpoch, ProvenEpochRow,
+    Status, StoreError, SELECT_PROVEN_EPOCHS,
+};
+use crate::fetcher::recursive_epoch_input::RecursiveEpochOutput;
+
+#[derive(Debug)]
+pub struct PostgresStore {
+    pool: Pool<Postgres>,
+    blob_store: Option<BlobStore>,
+}
+
+impl PostgresStore {
+    pub async fn new(url: &str) -> Result<Self, StoreError> {
+        let pool = PgPoolOptions::new().connect(url).await?;
+        // Fails on applied versions that aren't in the repo, see the migration policy of `connect`
+        sqlx::migrate!("../../migrations/postgres")
+            .run(&pool)
+            .await?;
+        Ok(Self {
+            pool,
+            blob_store: None,
+        })
+    }
+
+    /// Moves an epoch update to `next` if the transition is allowed, and records it as an event
+    async fn transition(
+        &self,
+        uuid: &str,
+        next: Status,
+        error_reason: Option<&str>,
+    ) -> Result<(), StoreError> {
+        let mut tx = self.pool.begin().await?;
+
+        let current: String =
+            sqlx::query_scalar("SELECT status FROM epoch_updates WHERE uuid = $1 FOR UPDATE")
+                .bind(uuid)
+                .fetch_optional(&mut *tx)
+                .await?
+                .ok_or_else(|| StoreError::EpochUpdateNotFound(uuid.to_string()))?;
+        check_transition(uuid, &current, next)?;
+
+        sqlx::query(
+            "UPDATE epoch_updates SET status = $1, error_reason = COALESCE($2, error_reason) WHERE uuid = $3",
+        )
+        .bind(next.as_str())
+        .bind(error_reason)
+        .bind(uuid)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query(
+            "INSERT INTO epoch_update_events (uuid, from_status, to_status, error_reason, created_at)
+             VALUES ($1, $2, $3, $4, $5)",
+        )
+        .bind(uuid)
+        .bind(current)
+        .bind(next.as_str())
+        .bind(error_reason)
+        .bind(now_millis())
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Writes large proofs to the blob store instead of the database
+    pub fn with_blob_store(mut self, blob_store: BlobStore) -> Self {
+        self.blob_store = Some(blob_store);
+        self
+    }
+}
+
+#[async_trait]
+impl EpochStore for PostgresStore {
+    async fn create_epoch_update(
+        &self,
+        epoch_number: u64,
+        slot_number: u64,
+        outputs: RecursiveEpochOutput,
+        execution_timestamp: u64,
+    ) -> Result<String, StoreError> {
+        let uuid = Uuid::new_v4().to_string();
+        let outputs_json = serde_json::to_string(&outputs)?;
+        let columns = OutputColumns::from(&outputs);
+
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            "INSERT INTO epoch_updates (
+                uuid, epoch_number, slot_number, outputs, status, beacon_height, execution_height,
+                execution_block_hash, beacon_header_root, current_committee_hash, next_committee_hash,
+                execution_timestamp
+             ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)",
+        )
+        .bind(&uuid)
+        .bind(epoch_number as i64)
+        .bind(slot_number as i64)
+        .bind(outputs_json)
+        .bind(Status::Fetching.as_str())
+        .bind(columns.beacon_height)
+        .bind(columns.execution_height)
+        .bind(columns.execution_block_hash)
+        .bind(columns.beacon_header_root)
+        .bind(columns.current_committee_hash)
+        .bind(columns.next_committee_hash)
+        .bind(execution_timestamp as i64)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO epoch_update_events (uuid, from_status, to_status, created_at) VALUES ($1, NULL, $2, $3)",
+        )
+        .bind(&uuid)
+        .bind(Status::Fetching.as_str())
+        .bind(now_millis())
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(uuid)
+    }
+
+    async fn update_outputs(
+        &self,
+        uuid: &str,
+        outputs: &RecursiveEpochOutput,
+    ) -> Result<(), StoreError> {
+        let outputs_json = serde_json::to_string(outputs)?;
+        let columns = OutputColumns::from(outputs);
+
+        sqlx::query(
+            "UPDATE epoch_updates
+             SET outputs = $1, beacon_height = $2, execution_height = $3, execution_block_hash = $4,
+                 beacon_header_root = $5, current_committee_hash = $6, next_committee_hash = $7
+             WHERE uuid = $8",
+        )
+        .bind(outputs_json)
+        .bind(columns.beacon_height)
+        .bind(columns.execution_height)
+        .bind(columns.execution_block_hash)
+        .bind(columns.beacon_header_root)
+        .bind(columns.current_committee_hash)
+        .bind(columns.next_committee_hash)
+        .bind(uuid)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn add_atlantic_id(&self, uuid: &str, atlantic_id: &str) -> Result<(), StoreError> {
+        sqlx::query("UPDATE epoch_updates SET atlantic_id = $1 WHERE uuid = $2")
+            .bind(atlantic_id)
+            .bind(uuid)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn add_proof(&self, proof_json: &str) -> Result<i64, StoreError> {
+        let proof = EncodedProof::new(proof_json)?;
+
+        // Proofs stored before content addressing are only known by their JSON
+        let existing = sqlx::query_scalar(
+            "SELECT id FROM proofs
+             WHERE content_hash = $1 OR (content_hash IS NULL AND proof = $2)
+             ORDER BY id ASC
+             LIMIT 1",
+        )
+        .bind(&proof.hash)
+        .bind(proof_json)
+        .fetch_optional(&self.pool)
+        .await?;
+        if let Some(id) = existing {
+            return Ok(id);
+        }
+
+        let blob_store = self
+            .blob_store
+            .as_ref()
+            .filter(|blob_store| blob_store.accepts(&proof));
+        let (data, blob_path) = match blob_store {
+            Some(_) => (None, Some(proof.blob_name())),
+            None => (Some(proof.data.as_slice()), None),
+        };
+
+        let mut tx = self.pool.begin().await?;
+        // A concurrent insert of the same content waits on this row, so only the transaction that
+        // inserted it writes the blob
+        let inserted = sqlx::query(
+            "INSERT INTO proof_contents (hash, data, blob_path, size) VALUES ($1, $2, $3, $4)
+             ON CONFLICT (hash) DO NOTHING",
+        )
+        .bind(&proof.hash)
+        .bind(data)
+        .bind(blob_path)
+        .bind(proof.size)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected()
+            == 1;
+        // A concurrent insert of the same proof resolves to the existing row
+        let id = sqlx::query_scalar(
+            "INSERT INTO proofs (proof, content_hash) VALUES ('', $1)
+             ON CONFLICT (content_hash) DO UPDATE SET content_hash = excluded.content_hash
+             RETURNING id",
+        )
+        .bind(&proof.hash)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let blob_store = blob_store.filter(|_| inserted);
+        if let Some(blob_store) = blob_store {
+            // Dropping the transaction on a failed write rolls the rows back
+            blob_store.write(&proof).await?;
+        }
+        if let Err(e) = tx.commit().await {
+            if let Some(blob_store) = blob_store {
+                blob_store.remove(&proof).await;
+            }
+            return Err(e.into());
+        }
+
+        Ok(id)
+    }
+
+    async fn update_proof_id(&self, uuid: &str, proof_id: i64) -> Result<(), StoreError> {
+        sqlx::query("UPDATE epoch_updates SET proof_id = $1 WHERE uuid = $2")
+            .bind(proof_id)
+            .bind(uuid)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_status(&self, uuid: &str, status: Status) -> Result<(), StoreError> {
+        self.transition(uuid, status, None).await
+    }
+
+    async fn update_error(&self, uuid: &str, error_reason: &str) -> Result<(), StoreError> {
+        self.transition(uuid, Status::Error, Some(error_reason))
+            .await
+    }
+
+    async fn get_epoch_update_events(
+        &self,
+        uuid: &str,
+    ) -> Result<Vec<EpochUpdateEvent>, StoreError> {
+        let events = sqlx::query_as(
+            "SELECT id, uuid, from_status, to_status, error_reason, created_at
+             FROM epoch_update_events
+             WHERE uuid = $1
+             ORDER BY id ASC",
+        )
+        .bind(uuid)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(events)
+    }
+
+    async fn get_latest_epoch_update(&self) -> Result<Option<EpochUpdate>, StoreError> {
+        let row = sqlx::query_as::<_, EpochUpdateRow>(
+            "SELECT uuid, epoch_number, slot_number, outputs, atlantic_id, proof_id, status, error_reason, execution_timestamp
+             FROM epoch_updates
+             WHERE status != 'error'
+             ORDER BY slot_number DESC
+             LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn get_latest_proven_epoch_update(&self) -> Result<Option<EpochUpdate>, StoreError> {
+        let row = sqlx::query_as::<_, EpochUpdateRow>(
+            "SELECT uuid, epoch_number, slot_number, outputs, atlantic_id, proof_id, status, error_reason, execution_timestamp
+             FROM epoch_updates
+             WHERE status = 'done'
+             ORDER BY slot_number DESC
+             LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn get_proven_epoch_updates_without_execution_timestamp(
+        &self,
+    ) -> Result<Vec<EpochUpdate>, StoreError> {
+        let rows = sqlx::query_as::<_, EpochUpdateRow>(
+            "SELECT uuid, epoch_number, slot_number, outputs, atlantic_id, proof_id, status, error_reason, execution_timestamp
+             FROM epoch_updates
+             WHERE status = 'done' AND execution_timestamp IS NULL
+             ORDER BY slot_number DESC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let updates = rows.into_iter().map(EpochUpdate::from).collect();
+        Ok(updates)
+    }
+
+    async fn get_epoch_update_by_uuid(
+        &self,
+        uuid: &str,
+    ) -> Result<Option<EpochUpdate>, StoreError> {
+        let row = sqlx::query_as::<_, EpochUpdateRow>(
+            "SELECT uuid, epoch_number, slot_number, outputs, atlantic_id, proof_id, status, error_reason, execution_timestamp
+             FROM epoch_updates
+             WHERE uuid = $1",
+        )
+        .bind(uuid)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn get_proof(&self, proof_id: i64) -> Result<Option<Proof>, StoreError> {
+        let sql = format!("{SELECT_PROOFS} WHERE p.id = $1");
+        let row = sqlx::query_as::<_, ProofRow>(&sql)
+            .bind(proof_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        resolve_optional(row, self.blob_store.as_ref()).await
+    }
+
+    async fn get_all_epoch_updates(&self) -> Result<Vec<EpochUpdate>, StoreError> {
+        let rows = sqlx::query_as::<_, EpochUpdateRow>(
+            "SELECT uuid, epoch_number, slot_number, outputs, atlantic_id, proof_id, status, error_reason, execution_timestamp
+             FROM epoch_updates
+             ORDER BY slot_number DESC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let updates = rows.into_iter().map(EpochUpdate::from).collect();
+        Ok(updates)
+    }
+
+    async fn get_proof_by_beacon_height(&self, height: u64) -> Result<Option<Proof>, StoreError> {
+        let sql = format!(
+            "{SELECT_PROOFS} JOIN epoch_updates eu ON p.id = eu.proof_id WHERE eu.beacon_height = $1"
+        );
+        let row = sqlx::query_as::<_, ProofRow>(&sql)
+            .bind(height as i64)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        resolve_optional(row, self.blob_store.as_ref()).await
+    }
+
+    async fn get_proof_by_execution_height(
+        &self,
+        height: u64,
+    ) -> Result<Option<Proof>, StoreError> {
+        let sql = format!(
+            "{SELECT_PROOFS} JOIN epoch_updates eu ON p.id = eu.proof_id WHERE eu.execution_height = $1"
+        );
+        let row = sqlx::query_as::<_, ProofRow>(&sql)
+            .bind(height as i64)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        resolve_optional(row, self.blob_store.as_ref()).await
+    }
+
+    async fn add_execution_timestamp(&self, uuid: &str, timestamp: u64) -> Result<(), StoreError> {
+        sqlx::query("UPDATE epoch_updates SET execution_timestamp = $1 WHERE uuid = $2")
+            .bind(timestamp as i64)
+            .bind(uuid)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_nearest_proven_epoch_by_execution_height(
+        &self,
+        block_number: u64,
+    ) -> Result<Option<ProvenEpoch>, StoreError> {
+        let sql = format!(
+            "{SELECT_PROVEN_EPOCHS} AND execution_height >= $1 ORDER BY execution_height ASC LIMIT 1"
+        );
+        let row = sqlx::query_as::<_, ProvenEpochRow>(&sql)
+            .bind(block_number as i64)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map(ProvenEpoch::try_from).transpose()
+    }
+
+    async fn get_nearest_proven_epoch_by_timestamp(
+        &self,
+        timestamp: u64,
+    ) -> Result<Option<ProvenEpoch>, StoreError> {
+        let sql = format!(
+            "{SELECT_PROVEN_EPOCHS} AND execution_timestamp >= $1 ORDER BY execution_timestamp ASC LIMIT 1"
+        );
+        let row = sqlx::query_as::<_, ProvenEpochRow>(&sql)
+            .bind(timestamp as i64)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map(ProvenEpoch::try_from).transpose()
+    }
+
+    async fn get_proven_epochs_in_execution_range(
+        &self,
+        start: u64,
+        end: u64,
+    ) -> Result<Vec<ProvenEpoch>, StoreError> {
+        let sql = format!(
+            "{SELECT_PROVEN_EPOCHS} AND execution_height BETWEEN $1 AND $2 ORDER BY execution_height ASC"
+        );
+        let rows = sqlx::query_as::<_, ProvenEpochRow>(&sql)
+            .bind(start as i64)
+            .bind(end as i64)
+            .fetch_all(&self.pool)
+            .await?;
+
+        rows.into_iter().map(ProvenEpoch::try_from).collect()
+    }
+
+    async fn get_proven_epoch_by_beacon_header_root(
+        &self,
+        root: &FixedBytes<32>,
+    ) -> Result<Option<ProvenEpoch>, StoreError> {
+        let sql = format!("{SELECT_PROVEN_EPOCHS} AND beacon_header_root = $1");
+        let row = sqlx::query_as::<_, ProvenEpochRow>(&sql)
+            .bind(root.to_string())
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map(ProvenEpoch::try_from).transpose()
+    }
+
+    async fn get_proven_epoch_by_execution_block_hash(
+        &self,
+        block_hash: &FixedBytes<32>,
+    ) -> Result<Option<ProvenEpoch>, StoreError> {
+        let sql = format!("{SELECT_PROVEN_EPOCHS} AND execution_block_hash = $1");
+        let row = sqlx::query_as::<_, ProvenEpochRow>(&sql)
+            .bind(block_hash.to_string())
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map(ProvenEpoch::try_from).transpose()
+    }
+}
@@ -0,0 +1,172 @@
+//! Content-addressed proof storage
+//!
+//! Proofs are keyed by the SHA256 hash of their JSON and stored zstd compressed in the
+//! `proof_contents` table, so identical proofs are stored once. With a [`BlobStore`], large
+//! proofs are written to the filesystem and the table only keeps a reference to the file.
+
+use sha2::{Digest, Sha256};
+use sqlx::FromRow;
+use std::path::PathBuf;
+use tracing::warn;
+
+use super::{Proof, StoreError};
+
+/// zstd level used for proofs, the proofs are written once and read once per epoch
+const COMPRESSION_LEVEL: i32 = 9;
+/// Default compressed size from which proofs are written to the blob store
+pub const DEFAULT_BLOB_THRESHOLD: usize = 1 << 20;
+
+/// A proof encoded for storage
+#[derive(Debug)]
+pub(crate) struct EncodedProof {
+    /// Hex encoded SHA256 hash of the proof JSON
+    pub hash: String,
+    /// The zstd compressed proof JSON
+    pub data: Vec<u8>,
+    /// Size of the uncompressed proof JSON
+    pub size: i64,
+}
+
+impl EncodedProof {
+    pub fn new(proof_json: &str) -> Result<Self, StoreError> {
+        Ok(Self {
+            hash: hex::encode(Sha256::digest(proof_json.as_bytes())),
+            data: zstd::encode_all(proof_json.as_bytes(), COMPRESSION_LEVEL)?,
+            size: proof_json.len() as i64,
+        })
+    }
+
+    /// Name of the blob store file of the proof, relative to the blob directory
+    pub fn blob_name(&self) -> String {
+        format!("{}.zst", self.hash)
+    }
+}
+
+/// A proof as it is read from the database
+///
+/// `proof` holds the JSON of proofs stored before content addressing, for newer proofs the content
+/// is either inlined in `data` or written to the blob store at `blob_path`, and hashes to
+/// `content_hash`.
+#[derive(Debug, FromRow)]
+pub(crate) struct ProofRow {
+    pub id: i64,
+    pub proof: String,
+    pub content_hash: Option<String>,
+    pub data: Option<Vec<u8>>,
+    pub blob_path: Option<String>,
+}
+
+/// Selects the proof rows with their content, backends append their conditions to it
+pub(crate) const SELECT_PROOFS: &str = "SELECT p.id, p.proof, p.content_hash, c.data, c.blob_path
+     FROM proofs p
+     LEFT JOIN proof_contents c ON c.hash = p.content_hash";
+
+/// Filesystem storage of large proofs
+#[derive(Debug, Clone)]
+pub struct BlobStore {
+    dir: PathBuf,
+    threshold: usize,
+}
+
+impl BlobStore {
+    /// Creates a blob store in `dir`, storing proofs of at least `threshold` compressed bytes
+    pub fn new(dir: impl Into<PathBuf>, threshold: usize) -> Self {
+        Self {
+            dir: dir.into(),
+            threshold,
+        }
+    }
+
+    /// Returns whether a proof should be written to the blob store
+    pub(crate) fn accepts(&self, proof: &EncodedProof) -> bool {
+        proof.data.len() >= self.threshold
+    }
+
+    /// Writes the proof to the file named by [`EncodedProof::blob_name`]
+    ///
+    /// Backends only call this from the transaction that inserted the proof content, so there is a
+    /// single writer per proof. A file left behind by a crashed writer is reused.
+    pub(crate) async fn write(&self, proof: &EncodedProof) -> Result<(), StoreError> {
+        let name = proof.blob_name();
+        let path = self.dir.join(&name);
+        if tokio::fs::try_exists(&path).await? {
+            return Ok(());
+        }
+
+        tokio::fs::create_dir_all(&self.dir).await?;
+        // Write to a temporary file first, so a crash can't leave a truncated blob behind
+        let tmp = self.dir.join(format!("{name}.tmp"));
+        tokio::fs::write(&tmp, &proof.data).await?;
+        tokio::fs::rename(&tmp, &path).await?;
+        Ok(())
+    }
+
+    /// Removes the blob of a proof whose transaction failed, so it isn't left unreferenced
+    pub(crate) async fn remove(&self, proof: &EncodedProof) {
+        let path = self.dir.join(proof.blob_name());
+        if let Err(e) = tokio::fs::remove_file(&path).await {
+            warn!(
+                "Failed to remove unreferenced proof blob {}: {e}",
+                path.display()
+            );
+        }
+    }
+
+    async fn read(&self, blob_path: &str) -> Result<Vec<u8>, StoreError> {
+        Ok(tokio::fs::read(self.dir.join(blob_path)).await?)
+    }
+}
+
+/// Resolves the content of a proof row
+///
+/// # Arguments
+/// * `row` - The proof row
+/// * `blob_store` - The blob store of the backend, required if the proof was written to it
+///
+/// # Returns
+/// * `Result<Proof, StoreError>` - The proof with its JSON, checked against its content hash
+pub(crate) async fn resolve(
+    row: ProofRow,
+    blob_store: Option<&BlobStore>,
+) -> Result<Proof, StoreError> {
+    let data = match (row.data, row.blob_path) {
+        (Some(data), _) => data,
+        (None, Some(blob_path)) => {
+            let blob_store =
+                blob_store.ok_or_else(|| StoreError::MissingBlobStore(blob_path.clone()))?;
+            blob_store.read(&blob_path).await?
+        }
+        // Stored before content addressing
+        (None, None) => {
+            return Ok(Proof {
+                id: row.id,
+                proof: row.proof,
+            })
+        }
+    };
+
+    let proof = String::from_utf8(zstd::decode_all(data.as_slice())?)
+        .map_err(|e| StoreError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))?;
+
+    // A corrupted or swapped blob must not be served as the proof
+    let hash = hex::encode(Sha256::digest(proof.as_bytes()));
+    if row.content_hash.as_deref() != Some(hash.as_str()) {
+        return Err(StoreError::ProofHashMismatch {
+            id: row.id,
+            expected: row.content_hash.unwrap_or_default(),
+            actual: hash,
+        });
+    }
+    Ok(Proof { id: row.id, proof })
+}
+
+/// Resolves the content of an optional proof row
+pub(crate) async fn resolve_optional(
+    row: Option<ProofRow>,
+    blob_store: Option<&BlobStore>,
+) -> Result<Option<Proof>, StoreError> {
+    match row {
+        Some(row) => Ok(Some(resolve(row, blob_store).await?)),
+        None => Ok(None),
+    }
+}
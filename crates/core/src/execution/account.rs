@@ -0,0 +1,213 @@
+use alloy_primitives::{keccak256, Address, B256, U256};
+use alloy_rpc_types::EIP1186AccountProofResponse;
+use alloy_trie::{proof::verify_proof, Nibbles, TrieAccount, EMPTY_ROOT_HASH, KECCAK_EMPTY};
+use serde::{Deserialize, Serialize};
+
+use crate::clients::execution::ExecutionRpcClient;
+use crate::execution::{ExecutionError, ProvenExecutionHeader};
+
+/// Account data verified against the state root of a proven execution header
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerifiedAccount {
+    /// Address of the account
+    pub address: Address,
+    /// Execution block the account data was verified at
+    pub block_number: u64,
+    /// Number of transactions sent from the account
+    pub nonce: u64,
+    /// Balance of the account in Wei
+    pub balance: U256,
+    /// Root of the account's storage trie
+    pub storage_hash: B256,
+    /// Hash of the account's EVM code
+    pub code_hash: B256,
+}
+
+/// Storage slot value verified against the storage root of a verified account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerifiedStorageSlot {
+    /// Address of the account owning the slot
+    pub address: Address,
+    /// Execution block the slot was verified at
+    pub block_number: u64,
+    /// Storage slot key
+    pub key: B256,
+    /// Value stored in the slot
+    pub value: U256,
+}
+
+/// Account and storage proofs (`eth_getProof`) for a single account at a proven execution block
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountProof {
+    /// Execution block the proof was generated for
+    pub block_number: u64,
+    /// The requested account
+    pub address: Address,
+    /// The requested storage slots, in request order
+    pub keys: Vec<B256>,
+    /// The raw proof response of the execution node
+    pub proof: EIP1186AccountProofResponse,
+}
+
+impl AccountProof {
+    /// Fetches the account and storage proofs for the block of a proven execution header
+    ///
+    /// # Arguments
+    /// * `client` - Reference to the execution RPC client
+    /// * `header` - The proven execution header to fetch the proofs for
+    /// * `address` - The account to prove
+    /// * `keys` - The storage slots to prove
+    ///
+    /// # Returns
+    /// * `Result<AccountProof, ExecutionError>` - The fetched proof or an error
+    pub async fn fetch_proof(
+        client: &ExecutionRpcClient,
+        header: &ProvenExecutionHeader,
+        address: Address,
+        keys: Vec<B256>,
+    ) -> Result<AccountProof, ExecutionError> {
+        let proof = client
+            .get_proof(address, keys.clone(), header.block_number())
+            .await?;
+
+        Ok(AccountProof {
+            block_number: header.block_number(),
+            address,
+            keys,
+            proof,
+        })
+    }
+
+    /// Verifies the account proof against the state root of the proven header
+    ///
+    /// The proof is checked for the requested address, so a response for another account is
+    /// rejected.
+    ///
+    /// # Arguments
+    /// * `header` - The proven execution header the proof was fetched for
+    ///
+    /// # Returns
+    /// * `Result<VerifiedAccount, ExecutionError>` - The verified account or an error
+    pub fn verify_account(
+        &self,
+        header: &ProvenExecutionHeader,
+    ) -> Result<VerifiedAccount, ExecutionError> {
+        if self.proof.address != self.address {
+            return Err(ExecutionError::InvalidResponse(format!(
+                "Proof is for account {}, requested {}",
+                self.proof.address, self.address
+            )));
+        }
+        if self.block_number != header.block_number() {
+            return Err(ExecutionError::InvalidResponse(format!(
+                "Proof is for block {}, header is block {}",
+                self.block_number,
+                header.block_number()
+            )));
+        }
+
+        let mut account = TrieAccount {
+            nonce: self.proof.nonce,
+            balance: self.proof.balance,
+            storage_root: self.proof.storage_hash,
+            code_hash: self.proof.code_hash,
+        };
+
+        // Non-existent accounts are proven by exclusion
+        let expected_value = if is_empty_account(&account) {
+            account = TrieAccount::default();
+            None
+        } else {
+            Some(alloy_rlp::encode(account))
+        };
+
+        verify_proof(
+            header.state_root(),
+            Nibbles::unpack(keccak256(self.address)),
+            expected_value,
+            &self.proof.account_proof,
+        )
+        .map_err(ExecutionError::AccountProof)?;
+
+        Ok(VerifiedAccount {
+            address: self.address,
+            block_number: self.block_number,
+            nonce: account.nonce,
+            balance: account.balance,
+            storage_hash: account.storage_root,
+            code_hash: account.code_hash,
+        })
+    }
+
+    /// Verifies the account proof and all storage proofs against the proven header
+    ///
+    /// The storage proofs must match the requested slots, in request order.
+    ///
+    /// # Arguments
+    /// * `header` - The proven execution header the proof was fetched for
+    ///
+    /// # Returns
+    /// * `Result<(VerifiedAccount, Vec<VerifiedStorageSlot>), ExecutionError>` - The verified
+    ///   account and storage slots in request order, or an error
+    pub fn verify(
+        &self,
+        header: &ProvenExecutionHeader,
+    ) -> Result<(VerifiedAccount, Vec<VerifiedStorageSlot>), ExecutionError> {
+        let account = self.verify_account(header)?;
+
+        let proven_keys: Vec<B256> = self
+            .proof
+            .storage_proof
+            .iter()
+            .map(|storage_proof| storage_proof.key.as_b256())
+            .collect();
+        if proven_keys != self.keys {
+            return Err(ExecutionError::InvalidResponse(format!(
+                "Storage proofs are for slots {:?}, requested {:?}",
+                proven_keys, self.keys
+            )));
+        }
+
+        let slots = self
+            .proof
+            .storage_proof
+            .iter()
+            .map(|storage_proof| {
+                let key = storage_proof.key.as_b256();
+
+                // Zero values are not stored in the trie and are proven by exclusion
+                let expected_value = if storage_proof.value.is_zero() {
+                    None
+                } else {
+                    Some(alloy_rlp::encode(storage_proof.value))
+                };
+
+                verify_proof(
+                    account.storage_hash,
+                    Nibbles::unpack(keccak256(key)),
+                    expected_value,
+                    &storage_proof.proof,
+                )
+                .map_err(|error| ExecutionError::StorageProof { key, error })?;
+
+                Ok(VerifiedStorageSlot {
+                    address: account.address,
+                    block_number: account.block_number,
+                    key,
+                    value: storage_proof.value,
+                })
+            })
+            .collect::<Result<Vec<_>, ExecutionError>>()?;
+
+        Ok((account, slots))
+    }
+}
+
+/// Returns true if the account is empty and therefore not part of the state trie.
+/// Some clients report zero hashes instead of the empty code and storage hashes.
+fn is_empty_account(account: &TrieAccount) -> bool {
+    account.nonce == 0
+        && account.balance.is_zero()
+        && (account.code_hash == KECCAK_EMPTY || account.code_hash.is_zero())
+        && (account.storage_root == EMPTY_ROOT_HASH || account.storage_root.is_zero())
+}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use alloy_primitives::{address, Address, B256, U256};
+use serde::{Deserialize, Serialize};
+
+use crate::clients::execution::ExecutionRpcClient;
+use crate::execution::account::AccountProof;
+use crate::execution::{ExecutionError, ProvenExecutionHeader};
+
+/// Address of the EIP-4788 beacon roots contract
+pub const BEACON_ROOTS_ADDRESS: Address = address!("000F3df6D732807Ef1319fB7B8bB8522d0Beac02");
+/// Length of the ring buffers of the EIP-4788 contract
+pub const HISTORY_BUFFER_LENGTH: u64 = 8191;
+
+/// Parent beacon block root verified against the state of a proven execution header
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerifiedBeaconRoot {
+    /// Execution block whose state the root was read from
+    pub block_number: u64,
+    /// Timestamp of the execution block the root was stored by
+    pub timestamp: u64,
+    /// Root of the parent beacon block of the execution block at `timestamp`
+    pub parent_beacon_block_root: B256,
+}
+
+/// Storage proofs of the EIP-4788 ring buffers for a set of timestamps
+///
+/// The contract stores `timestamp` at `timestamp % 8191` and the parent beacon block root at
+/// `timestamp % 8191 + 8191`. Both slots are proven, so an overwritten entry is detected.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BeaconRootsProof {
+    /// Timestamps to derive the beacon roots for
+    pub timestamps: Vec<u64>,
+    /// Account and storage proofs of the beacon roots contract
+    pub proof: AccountProof,
+}
+
+impl BeaconRootsProof {
+    /// Fetches the ring buffer storage proofs for the block of a proven execution header
+    ///
+    /// # Arguments
+    /// * `client` - Reference to the execution RPC client
+    /// * `header` - The proven execution header whose state is read
+    /// * `timestamps` - The execution block timestamps to derive the beacon roots for
+    ///
+    /// # Returns
+    /// * `Result<BeaconRootsProof, ExecutionError>` - The fetched proof or an error
+    pub async fn fetch_proof(
+        client: &ExecutionRpcClient,
+        header: &ProvenExecutionHeader,
+        timestamps: Vec<u64>,
+    ) -> Result<BeaconRootsProof, ExecutionError> {
+        let keys = timestamps
+            .iter()
+            .flat_map(|&timestamp| {
+                let (timestamp_key, root_key) = storage_keys(timestamp);
+                [timestamp_key, root_key]
+            })
+            .collect();
+
+        let proof = AccountProof::fetch_proof(client, header, BEACON_ROOTS_ADDRESS, keys).await?;
+
+        Ok(BeaconRootsProof { timestamps, proof })
+    }
+
+    /// Verifies the storage proofs and derives the beacon roots of all requested timestamps
+    ///
+    /// # Arguments
+    /// * `header` - The proven execution header the proof was fetched for
+    ///
+    /// # Returns
+    /// * `Result<Vec<VerifiedBeaconRoot>, ExecutionError>` - The verified roots in request order
+    pub fn verify(
+        &self,
+        header: &ProvenExecutionHeader,
+    ) -> Result<Vec<VerifiedBeaconRoot>, ExecutionError> {
+        if self.proof.proof.address != BEACON_ROOTS_ADDRESS {
+            return Err(ExecutionError::InvalidResponse(format!(
+                "Proof is for account {}, expected the beacon roots contract",
+                self.proof.proof.address
+            )));
+        }
+
+        let (_, slots) = self.proof.verify(header)?;
+        let values: HashMap<B256, U256> = slots.into_iter().map(|s| (s.key, s.value)).collect();
+
+        self.timestamps
+            .iter()
+            .map(|&timestamp| {
+                let (timestamp_key, root_key) = storage_keys(timestamp);
+                let (stored_timestamp, root) =
+                    match (values.get(&timestamp_key), values.get(&root_key)) {
+                        (Some(stored_timestamp), Some(root)) => (*stored_timestamp, *root),
+                        _ => {
+                            return Err(ExecutionError::InvalidResponse(format!(
+                                "Missing storage proof for timestamp {}",
+                                timestamp
+                            )))
+                        }
+                    };
+
+                // The ring buffer entry was overwritten or never written
+                if stored_timestamp != U256::from(timestamp) {
+                    return Err(ExecutionError::HistoryEntryNotFound(timestamp));
+                }
+
+                Ok(VerifiedBeaconRoot {
+                    block_number: header.block_number(),
+                    timestamp,
+                    parent_beacon_block_root: B256::from(root.to_be_bytes()),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns the storage keys of the timestamp and root ring buffer entries of a timestamp
+fn storage_keys(timestamp: u64) -> (B256, B256) {
+    let index = timestamp % HISTORY_BUFFER_LENGTH;
+    (
+        B256::from(U256::from(index).to_be_bytes()),
+        B256::from(U256::from(index + HISTORY_BUFFER_LENGTH).to_be_bytes()),
+    )
+}
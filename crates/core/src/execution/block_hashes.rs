@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use alloy_primitives::{address, Address, B256, U256};
+use serde::{Deserialize, Serialize};
+
+use crate::clients::execution::ExecutionRpcClient;
+use crate::execution::account::AccountProof;
+use crate::execution::{ExecutionError, ProvenExecutionHeader};
+
+/// Address of the EIP-2935 history storage contract
+pub const HISTORY_STORAGE_ADDRESS: Address = address!("0000F90827F1C53a10cb7A02335B175320002935");
+/// Number of block hashes served by the EIP-2935 contract
+pub const HISTORY_SERVE_WINDOW: u64 = 8191;
+
+/// Historical block hash verified against the state of a proven execution header
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerifiedBlockHash {
+    /// Execution block whose state the hash was read from
+    pub proven_block_number: u64,
+    /// Number of the historical block
+    pub block_number: u64,
+    /// Hash of the historical block
+    pub block_hash: B256,
+}
+
+/// Storage proofs of the EIP-2935 history contract for a set of block numbers
+///
+/// The contract stores the hash of block `n` at slot `n % 8191`. Only the last 8191 blocks
+/// before the proven block are served, older entries have been overwritten.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockHashesProof {
+    /// Block numbers to prove the hashes of
+    pub block_numbers: Vec<u64>,
+    /// Account and storage proofs of the history contract
+    pub proof: AccountProof,
+}
+
+impl BlockHashesProof {
+    /// Fetches the history storage proofs for the block of a proven execution header
+    ///
+    /// # Arguments
+    /// * `client` - Reference to the execution RPC client
+    /// * `header` - The proven execution header whose state is read
+    /// * `block_numbers` - The historical blocks to prove the hashes of
+    ///
+    /// # Returns
+    /// * `Result<BlockHashesProof, ExecutionError>` - The fetched proof or an error
+    pub async fn fetch_proof(
+        client: &ExecutionRpcClient,
+        header: &ProvenExecutionHeader,
+        block_numbers: Vec<u64>,
+    ) -> Result<BlockHashesProof, ExecutionError> {
+        for &block_number in &block_numbers {
+            check_serve_window(header, block_number)?;
+        }
+
+        let keys = block_numbers.iter().map(|&n| storage_key(n)).collect();
+        let proof =
+            AccountProof::fetch_proof(client, header, HISTORY_STORAGE_ADDRESS, keys).await?;
+
+        Ok(BlockHashesProof {
+            block_numbers,
+            proof,
+        })
+    }
+
+    /// Verifies the storage proofs and returns the hashes of all requested blocks
+    ///
+    /// # Arguments
+    /// * `header` - The proven execution header the proof was fetched for
+    ///
+    /// # Returns
+    /// * `Result<Vec<VerifiedBlockHash>, ExecutionError>` - The verified hashes in request order
+    pub fn verify(
+        &self,
+        header: &ProvenExecutionHeader,
+    ) -> Result<Vec<VerifiedBlockHash>, ExecutionError> {
+        if self.proof.proof.address != HISTORY_STORAGE_ADDRESS {
+            return Err(ExecutionError::InvalidResponse(format!(
+                "Proof is for account {}, expected the history storage contract",
+                self.proof.proof.address
+            )));
+        }
+
+        let (_, slots) = self.proof.verify(header)?;
+        let values: HashMap<B256, U256> = slots.into_iter().map(|s| (s.key, s.value)).collect();
+
+        self.block_numbers
+            .iter()
+            .map(|&block_number| {
+                check_serve_window(header, block_number)?;
+
+                let value = values.get(&storage_key(block_number)).ok_or_else(|| {
+                    ExecutionError::InvalidResponse(format!(
+                        "Missing storage proof for block {}",
+                        block_number
+                    ))
+                })?;
+
+                // Blocks before the contract was activated are not stored
+                if value.is_zero() {
+                    return Err(ExecutionError::HistoryEntryNotFound(block_number));
+                }
+
+                Ok(VerifiedBlockHash {
+                    proven_block_number: header.block_number(),
+                    block_number,
+                    block_hash: B256::from(value.to_be_bytes()),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Ensures a block is within the serve window of the proven header's state
+fn check_serve_window(
+    header: &ProvenExecutionHeader,
+    block_number: u64,
+) -> Result<(), ExecutionError> {
+    if block_number >= header.block_number()
+        || block_number + HISTORY_SERVE_WINDOW < header.block_number()
+    {
+        return Err(ExecutionError::HistoryEntryNotFound(block_number));
+    }
+    Ok(())
+}
+
+/// Returns the storage key of the ring buffer entry of a block number
+fn storage_key(block_number: u64) -> B256 {
+    B256::from(U256::from(block_number % HISTORY_SERVE_WINDOW).to_be_bytes())
+}
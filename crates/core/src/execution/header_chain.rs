@@ -0,0 +1,114 @@
+use alloy_consensus::Header;
+use alloy_primitives::B256;
+use futures::{StreamExt, TryStreamExt};
+
+use crate::clients::execution::ExecutionRpcClient;
+use crate::execution::{ExecutionError, ProvenExecutionHeader};
+
+/// Maximum number of concurrent header requests
+const MAX_CONCURRENT_REQUESTS: usize = 16;
+
+/// Fetches the execution headers between two proven headers and verifies their hash links
+///
+/// # Arguments
+/// * `client` - Reference to the execution RPC client
+/// * `start` - The older proven execution header
+/// * `end` - The newer proven execution header
+///
+/// # Returns
+/// * `Result<Vec<Header>, ExecutionError>` - All headers from `start` to `end` (inclusive),
+///   ordered by block number
+pub async fn fetch_header_chain(
+    client: &ExecutionRpcClient,
+    start: &ProvenExecutionHeader,
+    end: &ProvenExecutionHeader,
+) -> Result<Vec<Header>, ExecutionError> {
+    let headers = fetch_headers_until(client, start.block_number(), end).await?;
+
+    // The chain has to end in the older proven header as well
+    let first_hash = headers[0].hash_slow();
+    if first_hash != start.block_hash() {
+        return Err(ExecutionError::HeaderChain {
+            block_number: start.block_number(),
+            expected: start.block_hash(),
+            actual: first_hash,
+        });
+    }
+
+    Ok(headers)
+}
+
+/// Fetches the execution headers from a block up to a proven header and verifies their hash links
+///
+/// # Arguments
+/// * `client` - Reference to the execution RPC client
+/// * `from_block` - The first block to fetch
+/// * `end` - The proven execution header the chain is linked to
+///
+/// # Returns
+/// * `Result<Vec<Header>, ExecutionError>` - All headers from `from_block` to `end` (inclusive),
+///   ordered by block number
+pub async fn fetch_headers_until(
+    client: &ExecutionRpcClient,
+    from_block: u64,
+    end: &ProvenExecutionHeader,
+) -> Result<Vec<Header>, ExecutionError> {
+    if from_block > end.block_number() {
+        return Err(ExecutionError::InvalidResponse(format!(
+            "Start block {} is after end block {}",
+            from_block,
+            end.block_number()
+        )));
+    }
+
+    let headers: Vec<Header> = futures::stream::iter(from_block..=end.block_number())
+        .map(|block_number| client.get_header(block_number))
+        .buffered(MAX_CONCURRENT_REQUESTS)
+        .try_collect()
+        .await?;
+
+    verify_header_chain(&headers, end.block_hash(), end.block_number())?;
+
+    Ok(headers)
+}
+
+/// Verifies that a list of headers forms a chain ending in a trusted block hash
+///
+/// The headers are hashed from the newest to the oldest, each hash has to match the
+/// `parent_hash` of the following header.
+///
+/// # Arguments
+/// * `headers` - The headers ordered by block number, ending with the trusted block
+/// * `anchor_hash` - The trusted hash of the last header, e.g. a proven `execution_header_root`
+/// * `anchor_number` - The block number of the last header
+pub fn verify_header_chain(
+    headers: &[Header],
+    anchor_hash: B256,
+    anchor_number: u64,
+) -> Result<(), ExecutionError> {
+    let mut expected_hash = anchor_hash;
+    let mut expected_number = anchor_number;
+
+    for header in headers.iter().rev() {
+        if header.number != expected_number {
+            return Err(ExecutionError::InvalidResponse(format!(
+                "Expected header {}, got {}",
+                expected_number, header.number
+            )));
+        }
+
+        let hash = header.hash_slow();
+        if hash != expected_hash {
+            return Err(ExecutionError::HeaderChain {
+                block_number: header.number,
+                expected: expected_hash,
+                actual: hash,
+            });
+        }
+
+        expected_hash = header.parent_hash;
+        expected_number = expected_number.saturating_sub(1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+//! Execution Layer Verification
+//!
+//! This module verifies Execution Layer data fetched from an untrusted RPC against execution
+//! payload headers proven by Bankai. A payload header is accepted once it is proven to be part of
+//! a beacon header with a proven `beacon_header_root`, and its roots are then used to check the
+//! returned proofs.
+
+use alloy_primitives::{FixedBytes, B256};
+use alloy_trie::proof::ProofVerificationError;
+use beacon_types::{ExecutionPayloadHeader, MainnetEthSpec};
+use thiserror::Error;
+use tree_hash::TreeHash;
+
+use crate::clients::execution::ExecutionRpcError;
+use crate::fetcher::execution_header_input::{ExecutionHeaderError, ExecutionHeaderProof};
+use crate::fetcher::recursive_epoch_input::{BeaconHeader, RecursiveEpochOutput};
+use crate::utils::merkle::MerkleError;
+
+pub mod account;
+pub mod beacon_roots;
+pub mod block_hashes;
+pub mod header_chain;
+pub mod receipts;
+pub mod rlp_header;
+pub mod transactions;
+pub mod withdrawals;
+
+/// An execution payload header proven to be part of a Bankai proven beacon header
+///
+/// The payload header is bound to the proven `beacon_header_root` through its SSZ inclusion proof
+/// in the beacon block body, so all of its fields can be trusted.
+#[derive(Debug, Clone)]
+pub struct ProvenExecutionHeader {
+    header: ExecutionPayloadHeader<MainnetEthSpec>,
+}
+
+impl ProvenExecutionHeader {
+    /// Creates a proven execution header from the payload inclusion proof of a proven beacon block
+    ///
+    /// The beacon header must hash to the proven `beacon_header_root`, the proof must be built for
+    /// its slot, the payload header must be included in its body root, and the payload block hash
+    /// must match the proven `execution_header_root`.
+    ///
+    /// # Arguments
+    /// * `beacon_header` - The beacon header of the proven slot
+    /// * `proof` - The inclusion proof of the execution payload header in the block body
+    /// * `outputs` - The outputs of a verified Bankai proof
+    ///
+    /// # Returns
+    /// * `Result<Self, ExecutionError>` - The proven header or the failed check
+    pub fn new(
+        beacon_header: &BeaconHeader,
+        proof: ExecutionHeaderProof,
+        outputs: &RecursiveEpochOutput,
+    ) -> Result<Self, ExecutionError> {
+        let beacon_header_root = FixedBytes::from_slice(beacon_header.tree_hash_root().as_slice());
+        if beacon_header_root != outputs.beacon_header_root {
+            return Err(ExecutionError::RootMismatch {
+                field: "beacon_header_root",
+                expected: outputs.beacon_header_root,
+                actual: beacon_header_root,
+            });
+        }
+
+        if proof.slot != beacon_header.slot {
+            return Err(ExecutionError::SlotMismatch {
+                expected: beacon_header.slot,
+                actual: proof.slot,
+            });
+        }
+
+        proof.verify(beacon_header.body_root)?;
+
+        let header = proof.execution_payload_header;
+        let block_hash = FixedBytes::from_slice(header.block_hash().0.as_slice());
+        if block_hash != outputs.execution_header_root {
+            return Err(ExecutionError::BlockHashMismatch {
+                expected: outputs.execution_header_root,
+                actual: block_hash,
+            });
+        }
+
+        Ok(Self { header })
+    }
+
+    /// Returns the underlying execution payload header
+    pub fn header(&self) -> &ExecutionPayloadHeader<MainnetEthSpec> {
+        &self.header
+    }
+
+    /// Returns the block hash of the execution block
+    pub fn block_hash(&self) -> B256 {
+        B256::from_slice(self.header.block_hash().0.as_slice())
+    }
+
+    /// Returns the number of the execution block
+    pub fn block_number(&self) -> u64 {
+        self.header.block_number()
+    }
+
+    /// Returns the timestamp of the execution block
+    pub fn timestamp(&self) -> u64 {
+        self.header.timestamp()
+    }
+
+    /// Returns the world state root of the execution block
+    pub fn state_root(&self) -> B256 {
+        B256::from_slice(self.header.state_root().as_slice())
+    }
+
+    /// Returns the SSZ root of the block's transaction list
+    pub fn transactions_root(&self) -> B256 {
+        B256::from_slice(self.header.transactions_root().as_slice())
+    }
+
+    /// Returns the root of the block's receipts trie
+    pub fn receipts_root(&self) -> B256 {
+        B256::from_slice(self.header.receipts_root().as_slice())
+    }
+
+    /// Returns the SSZ root of the block's withdrawal list, available from Capella onwards
+    pub fn withdrawals_root(&self) -> Option<B256> {
+        self.header
+            .withdrawals_root()
+            .ok()
+            .map(|root| B256::from_slice(root.as_slice()))
+    }
+}
+
+/// Possible errors that can occur while verifying Execution Layer data
+#[derive(Debug, Error)]
+pub enum ExecutionError {
+    /// Error communicating with the execution node
+    #[error("Execution RPC error: {0}")]
+    Rpc(#[from] ExecutionRpcError),
+    /// The payload header does not belong to the proven execution block
+    #[error("Block hash mismatch: expected {expected}, got {actual}")]
+    BlockHashMismatch {
+        expected: FixedBytes<32>,
+        actual: FixedBytes<32>,
+    },
+    /// The payload proof was built for another slot than the proven beacon header
+    #[error("Slot mismatch: expected {expected}, got {actual}")]
+    SlotMismatch { expected: u64, actual: u64 },
+    /// The account proof does not match the proven state root
+    #[error("Account proof verification failed: {0}")]
+    AccountProof(ProofVerificationError),
+    /// A storage proof does not match the account's storage root
+    #[error("Storage proof verification failed for slot {key}: {error}")]
+    StorageProof {
+        key: B256,
+        error: ProofVerificationError,
+    },
+    /// A receipt proof does not match the proven receipts root
+    #[error("Receipt proof verification failed: {0}")]
+    ReceiptProof(ProofVerificationError),
+    /// Data rebuilt from the RPC response does not match the proven header
+    #[error("{field} mismatch: expected {expected}, got {actual}")]
+    RootMismatch {
+        field: &'static str,
+        expected: B256,
+        actual: B256,
+    },
+    /// A header does not link to its successor or the proven header
+    #[error("Header chain broken at block {block_number}: expected hash {expected}, got {actual}")]
+    HeaderChain {
+        block_number: u64,
+        expected: B256,
+        actual: B256,
+    },
+    /// The history contract holds no entry for the requested key
+    #[error("No history entry found for {0}")]
+    HistoryEntryNotFound(u64),
+    /// The header predates the fork introducing the requested data
+    #[error("Unsupported fork: {0}")]
+    UnsupportedFork(String),
+    /// The requested index is not part of the block
+    #[error("Index {index} out of bounds for block with {len} entries")]
+    IndexOutOfBounds { index: usize, len: usize },
+    /// The payload header is not included in the proven beacon block
+    #[error("Payload proof verification failed: {0}")]
+    PayloadProof(#[from] ExecutionHeaderError),
+    /// Error decoding data returned by the RPC
+    #[error("Decoding error: {0}")]
+    Decode(String),
+    /// Error building a merkle tree
+    #[error("Merkle error: {0}")]
+    Merkle(#[from] MerkleError),
+    /// The RPC response does not match the request
+    #[error("Invalid response: {0}")]
+    InvalidResponse(String),
+}
@@ -0,0 +1,167 @@
+use alloy_consensus::ReceiptEnvelope;
+use alloy_network::eip2718::{Decodable2718, Encodable2718};
+use alloy_primitives::Bytes;
+use alloy_trie::{
+    proof::{verify_proof, ProofRetainer},
+    root::{adjust_index_for_rlp, ordered_trie_root_with_encoder},
+    HashBuilder, Nibbles,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::clients::execution::ExecutionRpcClient;
+use crate::execution::{ExecutionError, ProvenExecutionHeader};
+
+/// All receipts of a proven execution block, checked against the payload `receipts_root`
+#[derive(Debug, Clone)]
+pub struct ReceiptsProofBuilder {
+    block_number: u64,
+    receipts: Vec<Bytes>,
+}
+
+impl ReceiptsProofBuilder {
+    /// Fetches the receipts of the proven block and checks them against its `receipts_root`
+    ///
+    /// # Arguments
+    /// * `client` - Reference to the execution RPC client
+    /// * `header` - The proven execution header to fetch the receipts for
+    ///
+    /// # Returns
+    /// * `Result<ReceiptsProofBuilder, ExecutionError>` - The builder or a root mismatch
+    pub async fn fetch(
+        client: &ExecutionRpcClient,
+        header: &ProvenExecutionHeader,
+    ) -> Result<Self, ExecutionError> {
+        let receipts = client.get_block_receipts(header.block_number()).await?;
+        Self::new(header, &receipts)
+    }
+
+    /// Creates the builder from already fetched receipts
+    ///
+    /// # Arguments
+    /// * `header` - The proven execution header the receipts belong to
+    /// * `receipts` - The receipts, ordered by transaction index
+    pub fn new(
+        header: &ProvenExecutionHeader,
+        receipts: &[ReceiptEnvelope],
+    ) -> Result<Self, ExecutionError> {
+        let root =
+            ordered_trie_root_with_encoder(receipts, |receipt, buf| receipt.encode_2718(buf));
+        if root != header.receipts_root() {
+            return Err(ExecutionError::RootMismatch {
+                field: "receipts_root",
+                expected: header.receipts_root(),
+                actual: root,
+            });
+        }
+
+        Ok(Self {
+            block_number: header.block_number(),
+            receipts: receipts
+                .iter()
+                .map(|receipt| receipt.encoded_2718().into())
+                .collect(),
+        })
+    }
+
+    /// Returns the number of receipts in the block
+    pub fn len(&self) -> usize {
+        self.receipts.len()
+    }
+
+    /// Returns true if the block contains no receipts
+    pub fn is_empty(&self) -> bool {
+        self.receipts.is_empty()
+    }
+
+    /// Generates the inclusion proof of the receipt at the given index
+    ///
+    /// # Arguments
+    /// * `index` - The index of the receipt's transaction in the block
+    ///
+    /// # Returns
+    /// * `Result<ReceiptProof, ExecutionError>` - The inclusion proof or an error
+    pub fn proof(&self, index: usize) -> Result<ReceiptProof, ExecutionError> {
+        if index >= self.receipts.len() {
+            return Err(ExecutionError::IndexOutOfBounds {
+                index,
+                len: self.receipts.len(),
+            });
+        }
+
+        let target = receipt_key(index);
+        let mut hash_builder =
+            HashBuilder::default().with_proof_retainer(ProofRetainer::new(vec![target.clone()]));
+
+        // Leaves have to be inserted in key order, which differs from the index order for RLP keys
+        for i in 0..self.receipts.len() {
+            let index = adjust_index_for_rlp(i, self.receipts.len());
+            hash_builder.add_leaf(receipt_key(index), &self.receipts[index]);
+        }
+        hash_builder.root();
+
+        let proof = hash_builder
+            .take_proof_nodes()
+            .matching_nodes_sorted(&target)
+            .into_iter()
+            .map(|(_, node)| node)
+            .collect();
+
+        Ok(ReceiptProof {
+            block_number: self.block_number,
+            index: index as u64,
+            receipt: self.receipts[index].clone(),
+            proof,
+        })
+    }
+}
+
+/// Inclusion proof of a single receipt in the `receipts_root` of a proven execution header
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiptProof {
+    /// Execution block containing the receipt
+    pub block_number: u64,
+    /// Index of the receipt's transaction in the block
+    pub index: u64,
+    /// EIP-2718 encoded receipt
+    pub receipt: Bytes,
+    /// MPT nodes from the `receipts_root` down to the receipt leaf
+    pub proof: Vec<Bytes>,
+}
+
+impl ReceiptProof {
+    /// Verifies the proof against the proven header and decodes the receipt
+    ///
+    /// # Arguments
+    /// * `header` - The proven execution header the proof was generated for
+    ///
+    /// # Returns
+    /// * `Result<ReceiptEnvelope, ExecutionError>` - The verified receipt or an error
+    pub fn verify(
+        &self,
+        header: &ProvenExecutionHeader,
+    ) -> Result<ReceiptEnvelope, ExecutionError> {
+        if self.block_number != header.block_number() {
+            return Err(ExecutionError::InvalidResponse(format!(
+                "Proof is for block {}, header is block {}",
+                self.block_number,
+                header.block_number()
+            )));
+        }
+
+        verify_proof(
+            header.receipts_root(),
+            receipt_key(self.index as usize),
+            Some(self.receipt.to_vec()),
+            &self.proof,
+        )
+        .map_err(ExecutionError::ReceiptProof)?;
+
+        ReceiptEnvelope::decode_2718(&mut self.receipt.as_ref())
+            .map_err(|e| ExecutionError::Decode(e.to_string()))
+    }
+}
+
+/// Returns the trie key of a receipt, the RLP encoding of its index
+fn receipt_key(index: usize) -> Nibbles {
+    Nibbles::unpack(alloy_rlp::encode_fixed_size(&index))
+}
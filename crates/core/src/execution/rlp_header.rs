@@ -0,0 +1,120 @@
+use alloy_consensus::{Header, EMPTY_OMMER_ROOT_HASH};
+use alloy_primitives::{Address, Bloom, Bytes, B256, B64, U256};
+use beacon_types::{ExecutionPayloadHeader, MainnetEthSpec};
+
+use crate::execution::{ExecutionError, ProvenExecutionHeader};
+
+/// Reconstructs the full RLP execution header of a payload header
+///
+/// Most fields are taken from the payload header. The payload commits to the transactions and
+/// withdrawals as SSZ roots, so the MPT roots, the parent beacon block root and the requests hash
+/// are taken from the RPC header. Post-merge constants (ommers hash, difficulty and nonce) are
+/// filled in. The RPC fields are bound by recomputing the block hash, see `verify_rlp_header`.
+///
+/// # Arguments
+/// * `payload` - The execution payload header of the beacon block
+/// * `rpc_header` - The header of the same block as returned by an execution RPC
+///
+/// # Returns
+/// * `Result<Header, ExecutionError>` - The reconstructed header
+pub fn rlp_header_from_payload(
+    payload: &ExecutionPayloadHeader<MainnetEthSpec>,
+    rpc_header: &Header,
+) -> Result<Header, ExecutionError> {
+    macro_rules! common_fields {
+        ($h:expr) => {
+            Header {
+                parent_hash: B256::from_slice($h.parent_hash.0.as_slice()),
+                ommers_hash: EMPTY_OMMER_ROOT_HASH,
+                beneficiary: Address::from_slice($h.fee_recipient.as_slice()),
+                state_root: B256::from_slice($h.state_root.as_slice()),
+                transactions_root: rpc_header.transactions_root,
+                receipts_root: B256::from_slice($h.receipts_root.as_slice()),
+                logs_bloom: Bloom::from_slice(&$h.logs_bloom[..]),
+                difficulty: U256::ZERO,
+                number: $h.block_number,
+                gas_limit: $h.gas_limit,
+                gas_used: $h.gas_used,
+                timestamp: $h.timestamp,
+                extra_data: Bytes::copy_from_slice(&$h.extra_data[..]),
+                mix_hash: B256::from_slice($h.prev_randao.as_slice()),
+                nonce: B64::ZERO,
+                base_fee_per_gas: Some(u64::try_from($h.base_fee_per_gas).map_err(|_| {
+                    ExecutionError::InvalidResponse("Base fee exceeds u64".to_string())
+                })?),
+                withdrawals_root: None,
+                blob_gas_used: None,
+                excess_blob_gas: None,
+                parent_beacon_block_root: None,
+                requests_hash: None,
+            }
+        };
+    }
+
+    let header = match payload {
+        ExecutionPayloadHeader::Bellatrix(h) => common_fields!(h),
+        ExecutionPayloadHeader::Capella(h) => Header {
+            withdrawals_root: rpc_header.withdrawals_root,
+            ..common_fields!(h)
+        },
+        ExecutionPayloadHeader::Deneb(h) => Header {
+            withdrawals_root: rpc_header.withdrawals_root,
+            blob_gas_used: Some(h.blob_gas_used),
+            excess_blob_gas: Some(h.excess_blob_gas),
+            parent_beacon_block_root: rpc_header.parent_beacon_block_root,
+            ..common_fields!(h)
+        },
+        // Electra and Fulu payloads are the same as Deneb, the EL header adds the requests hash
+        ExecutionPayloadHeader::Electra(h) => Header {
+            withdrawals_root: rpc_header.withdrawals_root,
+            blob_gas_used: Some(h.blob_gas_used),
+            excess_blob_gas: Some(h.excess_blob_gas),
+            parent_beacon_block_root: rpc_header.parent_beacon_block_root,
+            requests_hash: rpc_header.requests_hash,
+            ..common_fields!(h)
+        },
+        ExecutionPayloadHeader::Fulu(h) => Header {
+            withdrawals_root: rpc_header.withdrawals_root,
+            blob_gas_used: Some(h.blob_gas_used),
+            excess_blob_gas: Some(h.excess_blob_gas),
+            parent_beacon_block_root: rpc_header.parent_beacon_block_root,
+            requests_hash: rpc_header.requests_hash,
+            ..common_fields!(h)
+        },
+    };
+
+    Ok(header)
+}
+
+/// Reconstructs the RLP header of a payload header and checks it hashes to the payload `block_hash`
+///
+/// # Arguments
+/// * `payload` - The execution payload header of the beacon block
+/// * `rpc_header` - The header of the same block as returned by an execution RPC
+///
+/// # Returns
+/// * `Result<Header, ExecutionError>` - The reconstructed header or a block hash mismatch
+pub fn verify_rlp_header(
+    payload: &ExecutionPayloadHeader<MainnetEthSpec>,
+    rpc_header: &Header,
+) -> Result<Header, ExecutionError> {
+    let header = rlp_header_from_payload(payload, rpc_header)?;
+
+    let expected = B256::from_slice(payload.block_hash().0.as_slice());
+    let actual = header.hash_slow();
+    if actual != expected {
+        return Err(ExecutionError::BlockHashMismatch { expected, actual });
+    }
+
+    Ok(header)
+}
+
+impl ProvenExecutionHeader {
+    /// Returns the full RLP header of the proven block, consistent with its payload header
+    ///
+    /// # Arguments
+    /// * `rpc_header` - The header of the same block as returned by an execution RPC
+    pub fn rlp_header(&self, rpc_header: &Header) -> Result<Header, ExecutionError> {
+        verify_rlp_header(self.header(), rpc_header)
+    }
+}
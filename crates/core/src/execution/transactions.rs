@@ -0,0 +1,175 @@
+use alloy_consensus::TxEnvelope;
+use alloy_network::eip2718::Decodable2718;
+use alloy_primitives::{Bytes, FixedBytes, B256};
+use serde::{Deserialize, Serialize};
+
+use crate::clients::execution::ExecutionRpcClient;
+use crate::execution::{ExecutionError, ProvenExecutionHeader};
+use crate::utils::merkle::sha256;
+
+/// Depth of the transaction list tree (`MAX_TRANSACTIONS_PER_PAYLOAD = 2^20`)
+const TRANSACTIONS_LIST_DEPTH: usize = 20;
+/// Depth of the chunk tree of a single transaction (`MAX_BYTES_PER_TRANSACTION = 2^30`, 32 bytes per chunk)
+const TRANSACTION_CHUNKS_DEPTH: usize = 25;
+
+/// All transactions of a proven execution block, checked against the payload `transactions_root`
+///
+/// The payload header commits to the transactions as SSZ `List[ByteList[2^30], 2^20]`, so the
+/// proofs emitted by this builder are SSZ merkle paths rather than MPT proofs.
+#[derive(Debug, Clone)]
+pub struct TransactionsProofBuilder {
+    block_number: u64,
+    transactions: Vec<Bytes>,
+    transaction_roots: Vec<FixedBytes<32>>,
+}
+
+impl TransactionsProofBuilder {
+    /// Fetches the transactions of the proven block and checks them against its `transactions_root`
+    ///
+    /// # Arguments
+    /// * `client` - Reference to the execution RPC client
+    /// * `header` - The proven execution header to fetch the transactions for
+    ///
+    /// # Returns
+    /// * `Result<TransactionsProofBuilder, ExecutionError>` - The builder or a root mismatch
+    pub async fn fetch(
+        client: &ExecutionRpcClient,
+        header: &ProvenExecutionHeader,
+    ) -> Result<Self, ExecutionError> {
+        let transactions = client.get_block_transactions(header.block_number()).await?;
+        Self::new(header, transactions)
+    }
+
+    /// Creates the builder from already fetched, EIP-2718 encoded transactions
+    ///
+    /// # Arguments
+    /// * `header` - The proven execution header the transactions belong to
+    /// * `transactions` - The encoded transactions, ordered by index
+    pub fn new(
+        header: &ProvenExecutionHeader,
+        transactions: Vec<Bytes>,
+    ) -> Result<Self, ExecutionError> {
+        let transaction_roots = transactions
+            .iter()
+            .map(|transaction| transaction_root(transaction))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let root = sha256::mix_in_length(
+            &sha256::merkleize(&transaction_roots, TRANSACTIONS_LIST_DEPTH)?,
+            transaction_roots.len(),
+        );
+        if root != header.transactions_root() {
+            return Err(ExecutionError::RootMismatch {
+                field: "transactions_root",
+                expected: header.transactions_root(),
+                actual: root,
+            });
+        }
+
+        Ok(Self {
+            block_number: header.block_number(),
+            transactions,
+            transaction_roots,
+        })
+    }
+
+    /// Returns the number of transactions in the block
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns true if the block contains no transactions
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    /// Generates the inclusion proof of the transaction at the given index
+    ///
+    /// # Arguments
+    /// * `index` - The index of the transaction in the block
+    ///
+    /// # Returns
+    /// * `Result<TransactionProof, ExecutionError>` - The inclusion proof or an error
+    pub fn proof(&self, index: usize) -> Result<TransactionProof, ExecutionError> {
+        if index >= self.transactions.len() {
+            return Err(ExecutionError::IndexOutOfBounds {
+                index,
+                len: self.transactions.len(),
+            });
+        }
+
+        let mut path = sha256::generate_path_with_depth(
+            &self.transaction_roots,
+            index,
+            TRANSACTIONS_LIST_DEPTH,
+        )?;
+        path.push(sha256::length_chunk(self.transaction_roots.len()));
+
+        Ok(TransactionProof {
+            block_number: self.block_number,
+            index: index as u64,
+            transaction: self.transactions[index].clone(),
+            path,
+        })
+    }
+}
+
+/// Inclusion proof of a single transaction in the `transactions_root` of a proven execution header
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionProof {
+    /// Execution block containing the transaction
+    pub block_number: u64,
+    /// Index of the transaction in the block
+    pub index: u64,
+    /// EIP-2718 encoded transaction
+    pub transaction: Bytes,
+    /// SSZ merkle path from the transaction root to the `transactions_root`, ending with the list length
+    pub path: Vec<FixedBytes<32>>,
+}
+
+impl TransactionProof {
+    /// Verifies the proof against the proven header and decodes the transaction
+    ///
+    /// # Arguments
+    /// * `header` - The proven execution header the proof was generated for
+    ///
+    /// # Returns
+    /// * `Result<TxEnvelope, ExecutionError>` - The verified transaction or an error
+    pub fn verify(&self, header: &ProvenExecutionHeader) -> Result<TxEnvelope, ExecutionError> {
+        if self.block_number != header.block_number() {
+            return Err(ExecutionError::InvalidResponse(format!(
+                "Proof is for block {}, header is block {}",
+                self.block_number,
+                header.block_number()
+            )));
+        }
+
+        if self.path.len() != TRANSACTIONS_LIST_DEPTH + 1
+            || self.index >= 1 << TRANSACTIONS_LIST_DEPTH
+        {
+            return Err(ExecutionError::InvalidResponse(
+                "Malformed transaction proof".to_string(),
+            ));
+        }
+
+        let leaf = transaction_root(&self.transaction)?;
+        let root = sha256::hash_path(self.path.clone(), leaf, self.index);
+        if root != header.transactions_root() {
+            return Err(ExecutionError::RootMismatch {
+                field: "transactions_root",
+                expected: header.transactions_root(),
+                actual: root,
+            });
+        }
+
+        TxEnvelope::decode_2718(&mut self.transaction.as_ref())
+            .map_err(|e| ExecutionError::Decode(e.to_string()))
+    }
+}
+
+/// Computes the SSZ root of a single transaction (`ByteList[MAX_BYTES_PER_TRANSACTION]`)
+fn transaction_root(transaction: &[u8]) -> Result<B256, ExecutionError> {
+    let chunks = sha256::pack_bytes(transaction);
+    let root = sha256::merkleize(&chunks, TRANSACTION_CHUNKS_DEPTH)?;
+    Ok(sha256::mix_in_length(&root, transaction.len()))
+}
@@ -0,0 +1,182 @@
+use alloy_primitives::{FixedBytes, B256};
+use alloy_rpc_types::Withdrawal;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::execution::ExecutionRpcClient;
+use crate::execution::{ExecutionError, ProvenExecutionHeader};
+use crate::utils::merkle::sha256;
+
+/// Depth of the withdrawal list tree (`MAX_WITHDRAWALS_PER_PAYLOAD = 2^4`)
+const WITHDRAWALS_DEPTH: usize = 4;
+
+/// All withdrawals of a proven execution block, checked against the payload `withdrawals_root`
+///
+/// The payload header commits to the withdrawals as SSZ `List[Withdrawal, 16]`, so the proofs
+/// emitted by this builder are SSZ merkle paths.
+#[derive(Debug, Clone)]
+pub struct WithdrawalsProofBuilder {
+    block_number: u64,
+    withdrawals: Vec<Withdrawal>,
+    leaves: Vec<FixedBytes<32>>,
+}
+
+impl WithdrawalsProofBuilder {
+    /// Fetches the withdrawals of the proven block and checks them against its `withdrawals_root`
+    ///
+    /// # Arguments
+    /// * `client` - Reference to the execution RPC client
+    /// * `header` - The proven execution header to fetch the withdrawals for
+    ///
+    /// # Returns
+    /// * `Result<WithdrawalsProofBuilder, ExecutionError>` - The builder or a root mismatch
+    pub async fn fetch(
+        client: &ExecutionRpcClient,
+        header: &ProvenExecutionHeader,
+    ) -> Result<Self, ExecutionError> {
+        let withdrawals = client.get_block_withdrawals(header.block_number()).await?;
+        Self::new(header, withdrawals)
+    }
+
+    /// Creates the builder from already fetched withdrawals
+    ///
+    /// # Arguments
+    /// * `header` - The proven execution header the withdrawals belong to
+    /// * `withdrawals` - The withdrawals, ordered by their position in the payload
+    pub fn new(
+        header: &ProvenExecutionHeader,
+        withdrawals: Vec<Withdrawal>,
+    ) -> Result<Self, ExecutionError> {
+        let expected = withdrawals_root(header)?;
+
+        let leaves: Vec<FixedBytes<32>> = withdrawals.iter().map(withdrawal_root).collect();
+        let root = sha256::mix_in_length(
+            &sha256::merkleize(&leaves, WITHDRAWALS_DEPTH)?,
+            leaves.len(),
+        );
+        if root != expected {
+            return Err(ExecutionError::RootMismatch {
+                field: "withdrawals_root",
+                expected,
+                actual: root,
+            });
+        }
+
+        Ok(Self {
+            block_number: header.block_number(),
+            withdrawals,
+            leaves,
+        })
+    }
+
+    /// Returns the verified withdrawals of the block
+    pub fn withdrawals(&self) -> &[Withdrawal] {
+        &self.withdrawals
+    }
+
+    /// Generates the inclusion proof of the withdrawal at the given position in the payload
+    ///
+    /// # Arguments
+    /// * `position` - The position of the withdrawal in the payload
+    ///
+    /// # Returns
+    /// * `Result<WithdrawalProof, ExecutionError>` - The inclusion proof or an error
+    pub fn proof(&self, position: usize) -> Result<WithdrawalProof, ExecutionError> {
+        if position >= self.withdrawals.len() {
+            return Err(ExecutionError::IndexOutOfBounds {
+                index: position,
+                len: self.withdrawals.len(),
+            });
+        }
+
+        let mut path = sha256::generate_path_with_depth(&self.leaves, position, WITHDRAWALS_DEPTH)?;
+        path.push(sha256::length_chunk(self.leaves.len()));
+
+        Ok(WithdrawalProof {
+            block_number: self.block_number,
+            position: position as u64,
+            withdrawal: self.withdrawals[position],
+            path,
+        })
+    }
+}
+
+/// Inclusion proof of a single withdrawal in the `withdrawals_root` of a proven execution header
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WithdrawalProof {
+    /// Execution block containing the withdrawal
+    pub block_number: u64,
+    /// Position of the withdrawal in the payload
+    pub position: u64,
+    /// The withdrawal (index, validator index, address and amount in Gwei)
+    pub withdrawal: Withdrawal,
+    /// SSZ merkle path from the withdrawal root to the `withdrawals_root`, ending with the list length
+    pub path: Vec<FixedBytes<32>>,
+}
+
+impl WithdrawalProof {
+    /// Verifies the withdrawal against the proven header
+    ///
+    /// # Arguments
+    /// * `header` - The proven execution header the proof was generated for
+    ///
+    /// # Returns
+    /// * `Result<Withdrawal, ExecutionError>` - The verified withdrawal or an error
+    pub fn verify(&self, header: &ProvenExecutionHeader) -> Result<Withdrawal, ExecutionError> {
+        if self.block_number != header.block_number() {
+            return Err(ExecutionError::InvalidResponse(format!(
+                "Proof is for block {}, header is block {}",
+                self.block_number,
+                header.block_number()
+            )));
+        }
+
+        if self.path.len() != WITHDRAWALS_DEPTH + 1 || self.position >= 1 << WITHDRAWALS_DEPTH {
+            return Err(ExecutionError::InvalidResponse(
+                "Malformed withdrawal proof".to_string(),
+            ));
+        }
+
+        let expected = withdrawals_root(header)?;
+        let root = sha256::hash_path(
+            self.path.clone(),
+            withdrawal_root(&self.withdrawal),
+            self.position,
+        );
+        if root != expected {
+            return Err(ExecutionError::RootMismatch {
+                field: "withdrawals_root",
+                expected,
+                actual: root,
+            });
+        }
+
+        Ok(self.withdrawal)
+    }
+}
+
+/// Returns the withdrawals root of the header, failing for pre-Capella payloads
+fn withdrawals_root(header: &ProvenExecutionHeader) -> Result<B256, ExecutionError> {
+    header.withdrawals_root().ok_or_else(|| {
+        ExecutionError::UnsupportedFork(format!(
+            "Block {} has no withdrawals root",
+            header.block_number()
+        ))
+    })
+}
+
+/// Computes the SSZ root of a withdrawal container (index, validator_index, address, amount)
+fn withdrawal_root(withdrawal: &Withdrawal) -> FixedBytes<32> {
+    let mut address = [0u8; 32];
+    address[..20].copy_from_slice(withdrawal.address.as_slice());
+
+    sha256::hash_pair(
+        &sha256::hash_pair(
+            &sha256::uint64_chunk(withdrawal.index),
+            &sha256::uint64_chunk(withdrawal.validator_index),
+        ),
+        &sha256::hash_pair(
+            &FixedBytes::from(address),
+            &sha256::uint64_chunk(withdrawal.amount),
+        ),
+    )
+}
@@ -0,0 +1,191 @@
+use alloy_primitives::FixedBytes;
+use beacon_state_proof::state_proof_fetcher::TreeHash;
+use beacon_types::{BeaconBlockBody, Error as BeaconStateError, MainnetEthSpec};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::clients::beacon_chain::{BeaconError, BeaconRpcClient};
+use crate::fetcher::recursive_epoch_input::{BeaconHeader, RecursiveEpochOutput};
+use crate::utils::merkle::{sha256, MerkleError};
+
+/// Field index of `blob_kzg_commitments` in the `BeaconBlockBody` container
+const BLOB_KZG_COMMITMENTS_LEAF_INDEX: u64 = 11;
+/// Depth of the `BeaconBlockBody` container tree (12 fields in Deneb, 13 in Electra)
+const BODY_DEPTH: usize = 4;
+/// Depth of the commitments list (`MAX_BLOB_COMMITMENTS_PER_BLOCK = 2^12`)
+const BLOB_KZG_COMMITMENTS_DEPTH: usize = 12;
+/// Depth of the full inclusion proof (`KZG_COMMITMENT_INCLUSION_PROOF_DEPTH`)
+const KZG_COMMITMENT_INCLUSION_PROOF_DEPTH: usize = BLOB_KZG_COMMITMENTS_DEPTH + 1 + BODY_DEPTH;
+
+/// Represents a proof of inclusion for a blob KZG commitment in a beacon block body
+///
+/// The proof has the same shape as the `kzg_commitment_inclusion_proof` of a blob sidecar
+/// and is verified against the `body_root` of a beacon header with a proven root.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlobCommitmentProof {
+    /// Slot number of the beacon block containing the commitment
+    pub slot: u64,
+    /// Index of the commitment in `blob_kzg_commitments`
+    pub index: u64,
+    /// The KZG commitment of the blob
+    pub commitment: FixedBytes<48>,
+    /// Merkle proof path from the commitment root to the body root
+    pub path: Vec<FixedBytes<32>>,
+}
+
+impl BlobCommitmentProof {
+    /// Fetches the block body of a proven header and constructs the commitment inclusion proof
+    ///
+    /// # Arguments
+    /// * `client` - Reference to the beacon node RPC client
+    /// * `header` - The proven beacon header containing the blob
+    /// * `index` - The index of the blob commitment in the block
+    ///
+    /// # Returns
+    /// * `Result<BlobCommitmentProof, BlobCommitmentError>` - The constructed proof or an error
+    pub async fn fetch_proof(
+        client: &BeaconRpcClient,
+        header: &BeaconHeader,
+        index: u64,
+    ) -> Result<BlobCommitmentProof, BlobCommitmentError> {
+        let body: BeaconBlockBody<MainnetEthSpec> = client.get_block_body(header.slot).await?;
+
+        let body_root = FixedBytes::from_slice(body.tree_hash_root().as_slice());
+        if body_root != header.body_root {
+            return Err(BlobCommitmentError::BodyRootMismatch {
+                expected: header.body_root,
+                actual: body_root,
+            });
+        }
+
+        Self::from_block_body(&body, header.slot, index)
+    }
+
+    /// Constructs the commitment inclusion proof of an already fetched block body
+    ///
+    /// # Arguments
+    /// * `body` - The beacon block body containing the commitment
+    /// * `slot` - The slot number of the block
+    /// * `index` - The index of the blob commitment in the block
+    ///
+    /// # Returns
+    /// * `Result<BlobCommitmentProof, BlobCommitmentError>` - The constructed proof or an error
+    pub fn from_block_body(
+        body: &BeaconBlockBody<MainnetEthSpec>,
+        slot: u64,
+        index: u64,
+    ) -> Result<BlobCommitmentProof, BlobCommitmentError> {
+        let commitments = body
+            .blob_kzg_commitments()
+            .map_err(BlobCommitmentError::BeaconState)?;
+
+        let commitment =
+            commitments
+                .get(index as usize)
+                .ok_or(BlobCommitmentError::IndexOutOfBounds {
+                    index,
+                    len: commitments.len(),
+                })?;
+
+        let leaves: Vec<FixedBytes<32>> = commitments
+            .iter()
+            .map(|commitment| FixedBytes::from_slice(commitment.tree_hash_root().as_slice()))
+            .collect();
+        let body_leaves: Vec<FixedBytes<32>> = body
+            .to_ref()
+            .body_merkle_leaves()
+            .into_iter()
+            .map(|leaf| FixedBytes::from_slice(leaf.as_slice()))
+            .collect();
+
+        let mut path =
+            sha256::generate_path_with_depth(&leaves, index as usize, BLOB_KZG_COMMITMENTS_DEPTH)?;
+        path.push(sha256::length_chunk(leaves.len()));
+        path.extend(sha256::generate_path_with_depth(
+            &body_leaves,
+            BLOB_KZG_COMMITMENTS_LEAF_INDEX as usize,
+            BODY_DEPTH,
+        )?);
+
+        Ok(BlobCommitmentProof {
+            slot,
+            index,
+            commitment: FixedBytes::from_slice(&commitment.0),
+            path,
+        })
+    }
+
+    /// Verifies the commitment against the body root of a proven beacon header
+    ///
+    /// The header is only trusted once it hashes to the proven `beacon_header_root`.
+    ///
+    /// # Arguments
+    /// * `header` - The beacon header the blob was included in
+    /// * `outputs` - The outputs of a verified Bankai proof of that header
+    pub fn verify(
+        &self,
+        header: &BeaconHeader,
+        outputs: &RecursiveEpochOutput,
+    ) -> Result<(), BlobCommitmentError> {
+        let header_root = FixedBytes::from_slice(header.tree_hash_root().as_slice());
+        if header_root != outputs.beacon_header_root {
+            return Err(BlobCommitmentError::HeaderRootMismatch {
+                expected: outputs.beacon_header_root,
+                actual: header_root,
+            });
+        }
+        if self.slot != header.slot {
+            return Err(BlobCommitmentError::InvalidProof(format!(
+                "Proof is for slot {}, header is slot {}",
+                self.slot, header.slot
+            )));
+        }
+        if self.path.len() != KZG_COMMITMENT_INCLUSION_PROOF_DEPTH
+            || self.index >= 1 << BLOB_KZG_COMMITMENTS_DEPTH
+        {
+            return Err(BlobCommitmentError::InvalidProof(
+                "Malformed commitment proof".to_string(),
+            ));
+        }
+
+        // A 48 byte commitment spans two chunks
+        let chunks = sha256::pack_bytes(self.commitment.as_slice());
+        let leaf = sha256::hash_pair(&chunks[0], &chunks[1]);
+
+        let index =
+            self.index | (BLOB_KZG_COMMITMENTS_LEAF_INDEX << (BLOB_KZG_COMMITMENTS_DEPTH + 1));
+        let root = sha256::hash_path(self.path.clone(), leaf, index);
+        if root != header.body_root {
+            return Err(BlobCommitmentError::BodyRootMismatch {
+                expected: header.body_root,
+                actual: root,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum BlobCommitmentError {
+    #[error("Beacon error: {0}")]
+    Beacon(#[from] BeaconError),
+    #[error("Beacon state error")]
+    BeaconState(BeaconStateError),
+    #[error("Merkle error: {0}")]
+    Merkle(#[from] MerkleError),
+    #[error("Beacon header root mismatch: expected {expected}, got {actual}")]
+    HeaderRootMismatch {
+        expected: FixedBytes<32>,
+        actual: FixedBytes<32>,
+    },
+    #[error("Body root mismatch: expected {expected}, got {actual}")]
+    BodyRootMismatch {
+        expected: FixedBytes<32>,
+        actual: FixedBytes<32>,
+    },
+    #[error("Commitment index {index} out of bounds for block with {len} blobs")]
+    IndexOutOfBounds { index: u64, len: usize },
+    #[error("Invalid proof: {0}")]
+    InvalidProof(String),
+}
@@ -1,16 +1,24 @@
-use crate::utils::merkle::sha256::hash_path;
+use crate::utils::merkle::sha256::{self, hash_path};
+use crate::utils::merkle::MerkleError;
 // use crate::utils::rpc::BeaconRpcClient;
 use crate::clients::beacon_chain::{BeaconError, BeaconRpcClient};
 use alloy_primitives::FixedBytes;
 use beacon_state_proof::state_proof_fetcher::TreeHash;
 use beacon_types::light_client_update::EXECUTION_PAYLOAD_INDEX;
 use beacon_types::{
-    BeaconBlockBody, Error as BeaconStateError, ExecPayload, ExecutionPayloadHeader, MainnetEthSpec,
+    BeaconBlockBody, ConsolidationRequest, DepositRequest, Error as BeaconStateError, ExecPayload,
+    ExecutionPayloadHeader, MainnetEthSpec, WithdrawalRequest,
 };
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 const EXECUTION_PAYLOAD_LEAF_INDEX: usize = 9;
+/// Field index of `execution_requests` in the Electra `BeaconBlockBody` container
+const EXECUTION_REQUESTS_LEAF_INDEX: u64 = 12;
+/// Depth of the `BeaconBlockBody` container tree (13 fields in Electra)
+const BODY_DEPTH: usize = 4;
+/// Depth of the `ExecutionRequests` container tree (deposits, withdrawals, consolidations)
+const EXECUTION_REQUESTS_DEPTH: usize = 2;
 
 /// Represents a proof of inclusion for an execution payload header in a beacon block
 ///
@@ -49,6 +57,22 @@ impl ExecutionHeaderProof {
         // Fetch the beacon block body for the specified slot
         let beacon_block_body: BeaconBlockBody<MainnetEthSpec> =
             client.get_block_body(slot).await?;
+
+        Self::from_block_body(&beacon_block_body, slot)
+    }
+
+    /// Constructs a merkle proof for the execution payload header of an already fetched block body
+    ///
+    /// # Arguments
+    /// * `beacon_block_body` - The beacon block body containing the execution payload
+    /// * `slot` - The slot number of the block
+    ///
+    /// # Returns
+    /// * `Result<ExecutionHeaderProof, Error>` - The constructed proof or an error
+    pub fn from_block_body(
+        beacon_block_body: &BeaconBlockBody<MainnetEthSpec>,
+        slot: u64,
+    ) -> Result<ExecutionHeaderProof, ExecutionHeaderError> {
         let root = beacon_block_body.tree_hash_root();
 
         // Extract the execution payload header
@@ -86,6 +110,250 @@ impl ExecutionHeaderProof {
 
         Ok(proof)
     }
+
+    /// Verifies the execution payload header against the body root of a beacon header
+    ///
+    /// The leaf is recomputed from the payload header, so every payload field is bound to the root.
+    ///
+    /// # Arguments
+    /// * `body_root` - The body root of the beacon header at `slot`
+    pub fn verify(&self, body_root: FixedBytes<32>) -> Result<(), ExecutionHeaderError> {
+        if self.path.len() != BODY_DEPTH || self.index != EXECUTION_PAYLOAD_LEAF_INDEX {
+            return Err(ExecutionHeaderError::InvalidProof(
+                "Malformed execution payload proof".to_string(),
+            ));
+        }
+
+        let leaf =
+            FixedBytes::from_slice(self.execution_payload_header.tree_hash_root().as_slice());
+        let root = hash_path(self.path.clone(), leaf, EXECUTION_PAYLOAD_LEAF_INDEX as u64);
+        if root != body_root {
+            return Err(ExecutionHeaderError::BodyRootMismatch {
+                expected: body_root,
+                actual: root,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The type of an Electra execution request, matching its field in `ExecutionRequests`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExecutionRequestType {
+    Deposit,
+    Withdrawal,
+    Consolidation,
+}
+
+impl ExecutionRequestType {
+    /// Returns the field index of the request list in the `ExecutionRequests` container
+    fn field_index(&self) -> u64 {
+        match self {
+            ExecutionRequestType::Deposit => 0,
+            ExecutionRequestType::Withdrawal => 1,
+            ExecutionRequestType::Consolidation => 2,
+        }
+    }
+
+    /// Returns the depth of the request list tree, derived from the maximum requests per payload
+    fn list_depth(&self) -> usize {
+        match self {
+            // MAX_DEPOSIT_REQUESTS_PER_PAYLOAD = 2^13
+            ExecutionRequestType::Deposit => 13,
+            // MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD = 2^4
+            ExecutionRequestType::Withdrawal => 4,
+            // MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD = 2^1
+            ExecutionRequestType::Consolidation => 1,
+        }
+    }
+}
+
+/// A single Electra execution request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ExecutionRequest {
+    Deposit(DepositRequest),
+    Withdrawal(WithdrawalRequest),
+    Consolidation(ConsolidationRequest),
+}
+
+impl ExecutionRequest {
+    /// Returns the type of the request
+    pub fn request_type(&self) -> ExecutionRequestType {
+        match self {
+            ExecutionRequest::Deposit(_) => ExecutionRequestType::Deposit,
+            ExecutionRequest::Withdrawal(_) => ExecutionRequestType::Withdrawal,
+            ExecutionRequest::Consolidation(_) => ExecutionRequestType::Consolidation,
+        }
+    }
+
+    /// Computes the SSZ root of the request
+    fn tree_hash_root(&self) -> FixedBytes<32> {
+        let root = match self {
+            ExecutionRequest::Deposit(request) => request.tree_hash_root(),
+            ExecutionRequest::Withdrawal(request) => request.tree_hash_root(),
+            ExecutionRequest::Consolidation(request) => request.tree_hash_root(),
+        };
+        FixedBytes::from_slice(root.as_slice())
+    }
+}
+
+/// Represents a proof of inclusion for an Electra execution request in a beacon block
+///
+/// The path walks from the request root through its request list (including the mixed in list
+/// length) and the `ExecutionRequests` container up to the beacon block body root.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionRequestProof {
+    /// Slot number of the beacon block containing the request
+    pub slot: u64,
+    /// Index of the request in its request list
+    pub index: u64,
+    /// The execution request
+    pub request: ExecutionRequest,
+    /// Merkle proof path from the request root to the body root
+    pub path: Vec<FixedBytes<32>>,
+}
+
+impl ExecutionRequestProof {
+    /// Fetches the block body of a slot and constructs the proof of an execution request
+    ///
+    /// # Arguments
+    /// * `client` - Reference to the beacon node RPC client
+    /// * `slot` - The slot number of the block
+    /// * `request_type` - The request list to prove the request in
+    /// * `index` - The index of the request in the list
+    ///
+    /// # Returns
+    /// * `Result<ExecutionRequestProof, ExecutionHeaderError>` - The constructed proof or an error
+    pub async fn fetch_proof(
+        client: &BeaconRpcClient,
+        slot: u64,
+        request_type: ExecutionRequestType,
+        index: u64,
+    ) -> Result<ExecutionRequestProof, ExecutionHeaderError> {
+        let beacon_block_body: BeaconBlockBody<MainnetEthSpec> =
+            client.get_block_body(slot).await?;
+
+        Self::from_block_body(&beacon_block_body, slot, request_type, index)
+    }
+
+    /// Constructs the proof of an execution request of an already fetched Electra block body
+    ///
+    /// # Arguments
+    /// * `beacon_block_body` - The beacon block body containing the request
+    /// * `slot` - The slot number of the block
+    /// * `request_type` - The request list to prove the request in
+    /// * `index` - The index of the request in the list
+    ///
+    /// # Returns
+    /// * `Result<ExecutionRequestProof, ExecutionHeaderError>` - The constructed proof or an error
+    pub fn from_block_body(
+        beacon_block_body: &BeaconBlockBody<MainnetEthSpec>,
+        slot: u64,
+        request_type: ExecutionRequestType,
+        index: u64,
+    ) -> Result<ExecutionRequestProof, ExecutionHeaderError> {
+        let requests = beacon_block_body
+            .execution_requests()
+            .map_err(ExecutionHeaderError::BeaconState)?;
+
+        let list: Vec<ExecutionRequest> = match request_type {
+            ExecutionRequestType::Deposit => requests
+                .deposits
+                .iter()
+                .cloned()
+                .map(ExecutionRequest::Deposit)
+                .collect(),
+            ExecutionRequestType::Withdrawal => requests
+                .withdrawals
+                .iter()
+                .cloned()
+                .map(ExecutionRequest::Withdrawal)
+                .collect(),
+            ExecutionRequestType::Consolidation => requests
+                .consolidations
+                .iter()
+                .cloned()
+                .map(ExecutionRequest::Consolidation)
+                .collect(),
+        };
+
+        let request =
+            list.get(index as usize)
+                .cloned()
+                .ok_or(ExecutionHeaderError::IndexOutOfBounds {
+                    index,
+                    len: list.len(),
+                })?;
+
+        // Hash each request list, mixing in its length
+        let leaves: Vec<FixedBytes<32>> = list.iter().map(|r| r.tree_hash_root()).collect();
+        let list_roots: Vec<FixedBytes<32>> = [
+            requests.deposits.tree_hash_root(),
+            requests.withdrawals.tree_hash_root(),
+            requests.consolidations.tree_hash_root(),
+        ]
+        .iter()
+        .map(|root| FixedBytes::from_slice(root.as_slice()))
+        .collect();
+        let body_leaves: Vec<FixedBytes<32>> = beacon_block_body
+            .to_ref()
+            .body_merkle_leaves()
+            .into_iter()
+            .map(|leaf| FixedBytes::from_slice(leaf.as_slice()))
+            .collect();
+
+        let mut path =
+            sha256::generate_path_with_depth(&leaves, index as usize, request_type.list_depth())?;
+        path.push(sha256::length_chunk(leaves.len()));
+        path.extend(sha256::generate_path_with_depth(
+            &list_roots,
+            request_type.field_index() as usize,
+            EXECUTION_REQUESTS_DEPTH,
+        )?);
+        path.extend(sha256::generate_path_with_depth(
+            &body_leaves,
+            EXECUTION_REQUESTS_LEAF_INDEX as usize,
+            BODY_DEPTH,
+        )?);
+
+        Ok(ExecutionRequestProof {
+            slot,
+            index,
+            request,
+            path,
+        })
+    }
+
+    /// Verifies the execution request against the body root of a proven beacon header
+    ///
+    /// # Arguments
+    /// * `body_root` - The body root of the proven beacon header at `slot`
+    pub fn verify(&self, body_root: FixedBytes<32>) -> Result<(), ExecutionHeaderError> {
+        let request_type = self.request.request_type();
+        let list_depth = request_type.list_depth();
+
+        if self.path.len() != list_depth + 1 + EXECUTION_REQUESTS_DEPTH + BODY_DEPTH
+            || self.index >= 1 << list_depth
+        {
+            return Err(ExecutionHeaderError::InvalidProof(
+                "Malformed execution request proof".to_string(),
+            ));
+        }
+
+        let index = self.index
+            | (request_type.field_index() << (list_depth + 1))
+            | (EXECUTION_REQUESTS_LEAF_INDEX << (list_depth + 1 + EXECUTION_REQUESTS_DEPTH));
+        let root = hash_path(self.path.clone(), self.request.tree_hash_root(), index);
+        if root != body_root {
+            return Err(ExecutionHeaderError::BodyRootMismatch {
+                expected: body_root,
+                actual: root,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Error)]
@@ -94,4 +362,15 @@ pub enum ExecutionHeaderError {
     Beacon(#[from] BeaconError),
     #[error("Beacon state error")]
     BeaconState(BeaconStateError),
+    #[error("Merkle error: {0}")]
+    Merkle(#[from] MerkleError),
+    #[error("Body root mismatch: expected {expected}, got {actual}")]
+    BodyRootMismatch {
+        expected: FixedBytes<32>,
+        actual: FixedBytes<32>,
+    },
+    #[error("Request index {index} out of bounds for list with {len} entries")]
+    IndexOutOfBounds { index: u64, len: usize },
+    #[error("Invalid proof: {0}")]
+    InvalidProof(String),
 }
@@ -0,0 +1,267 @@
+use alloy_primitives::FixedBytes;
+use beacon_state_proof::state_proof_fetcher::TreeHash;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::beacon_chain::BeaconRpcClient;
+use crate::fetcher::state_proofs::{
+    verify_state_path, BeaconStateProofBuilder, BeaconStateProofError, StateField,
+};
+use crate::utils::constants::{CAPELLA_FORK_SLOT, SLOTS_PER_HISTORICAL_ROOT};
+use crate::utils::merkle::sha256;
+
+/// Field index of `block_roots` in the `BeaconState` container
+const BLOCK_ROOTS_FIELD_INDEX: u64 = 5;
+/// Field index of `state_roots` in the `BeaconState` container
+const STATE_ROOTS_FIELD_INDEX: u64 = 6;
+/// Field index of `historical_summaries` in the `BeaconState` container
+const HISTORICAL_SUMMARIES_FIELD_INDEX: u64 = 27;
+
+/// Depth of the `block_roots` vector (`SLOTS_PER_HISTORICAL_ROOT = 2^13`)
+const BLOCK_ROOTS_DEPTH: usize = 13;
+/// Depth of the `historical_summaries` list (`HISTORICAL_ROOTS_LIMIT = 2^24`)
+const HISTORICAL_SUMMARIES_DEPTH: usize = 24;
+
+/// Where the block root of a historical slot is stored in the proven beacon state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HistoricalRootSource {
+    /// The slot is within the last `SLOTS_PER_HISTORICAL_ROOT` slots of the state
+    BlockRoots,
+    /// The slot is part of a completed period, summarized in `historical_summaries`
+    HistoricalSummaries,
+}
+
+/// Proof that a past block root is committed to by a proven beacon state
+///
+/// For empty slots the state stores the root of the latest block before the slot, so the proven
+/// root is always the root of the canonical chain head at that slot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoricalBlockProof {
+    /// Slot of the historical block
+    pub slot: u64,
+    /// Root of the historical block
+    pub block_root: FixedBytes<32>,
+    /// State field the block root is proven through
+    pub source: HistoricalRootSource,
+    /// Merkle path from the block root to the state root
+    pub path: Vec<FixedBytes<32>>,
+}
+
+impl HistoricalBlockProof {
+    /// Fetches the proof of a historical block root against a proven beacon state
+    ///
+    /// Slots of completed periods additionally require the state at the end of that period,
+    /// which has to be served by an archive node.
+    ///
+    /// # Arguments
+    /// * `client` - Reference to the beacon node RPC client
+    /// * `proven_state` - The beacon state of the proven header
+    /// * `slot` - The historical slot to prove
+    ///
+    /// # Returns
+    /// * `Result<HistoricalBlockProof, BeaconStateProofError>` - The proof or an error
+    pub async fn fetch_proof(
+        client: &BeaconRpcClient,
+        proven_state: &BeaconStateProofBuilder,
+        slot: u64,
+    ) -> Result<HistoricalBlockProof, BeaconStateProofError> {
+        if is_in_block_roots(slot, proven_state.slot) {
+            return Self::from_block_roots(proven_state, slot);
+        }
+
+        // The state at the first slot of the next period holds all block roots of the period
+        let summary_slot = (slot / SLOTS_PER_HISTORICAL_ROOT + 1) * SLOTS_PER_HISTORICAL_ROOT;
+        let summary_state = client.get_state(summary_slot).await?;
+        let summary_state = BeaconStateProofBuilder::from_state(summary_state, summary_slot)?;
+
+        Self::from_historical_summaries(proven_state, &summary_state, slot)
+    }
+
+    /// Builds the proof of a block root stored in the `block_roots` of the proven state
+    ///
+    /// # Arguments
+    /// * `proven_state` - The beacon state of the proven header
+    /// * `slot` - The historical slot to prove
+    pub fn from_block_roots(
+        proven_state: &BeaconStateProofBuilder,
+        slot: u64,
+    ) -> Result<HistoricalBlockProof, BeaconStateProofError> {
+        if !is_in_block_roots(slot, proven_state.slot) {
+            return Err(BeaconStateProofError::InvalidProof(format!(
+                "Slot {} is not part of the block roots of slot {}",
+                slot, proven_state.slot
+            )));
+        }
+
+        let (block_root, mut path) = block_roots_path(proven_state, slot)?;
+        path.extend(proven_state.field_path(BLOCK_ROOTS_FIELD_INDEX)?);
+
+        Ok(HistoricalBlockProof {
+            slot,
+            block_root,
+            source: HistoricalRootSource::BlockRoots,
+            path,
+        })
+    }
+
+    /// Builds the proof of a block root summarized in the `historical_summaries` of the proven state
+    ///
+    /// # Arguments
+    /// * `proven_state` - The beacon state of the proven header
+    /// * `summary_state` - The beacon state at the first slot after the period of `slot`
+    /// * `slot` - The historical slot to prove
+    pub fn from_historical_summaries(
+        proven_state: &BeaconStateProofBuilder,
+        summary_state: &BeaconStateProofBuilder,
+        slot: u64,
+    ) -> Result<HistoricalBlockProof, BeaconStateProofError> {
+        if slot < CAPELLA_FORK_SLOT {
+            return Err(BeaconStateProofError::InvalidProof(format!(
+                "Slot {} predates historical summaries",
+                slot
+            )));
+        }
+        if summary_state.slot != (slot / SLOTS_PER_HISTORICAL_ROOT + 1) * SLOTS_PER_HISTORICAL_ROOT
+        {
+            return Err(BeaconStateProofError::InvalidProof(format!(
+                "State at slot {} does not summarize slot {}",
+                summary_state.slot, slot
+            )));
+        }
+
+        let summaries: Vec<FixedBytes<32>> = proven_state
+            .state()
+            .historical_summaries()
+            .map_err(BeaconStateProofError::BeaconState)?
+            .iter()
+            .map(|summary| FixedBytes::from_slice(summary.tree_hash_root().as_slice()))
+            .collect();
+
+        let summary_index = historical_summary_index(slot);
+        if summary_index >= summaries.len() as u64 {
+            return Err(BeaconStateProofError::IndexOutOfBounds {
+                index: summary_index,
+                len: summaries.len(),
+            });
+        }
+
+        // A summary is the container (block_summary_root, state_summary_root), where both roots
+        // are the roots of the `block_roots` and `state_roots` vectors of the summarized state
+        let state_summary_root = summary_state
+            .field_root(STATE_ROOTS_FIELD_INDEX)
+            .ok_or_else(|| {
+                BeaconStateProofError::InvalidProof("Missing state roots field".to_string())
+            })?;
+
+        let (block_root, mut path) = block_roots_path(summary_state, slot)?;
+        path.push(state_summary_root);
+        path.extend(sha256::generate_path_with_depth(
+            &summaries,
+            summary_index as usize,
+            HISTORICAL_SUMMARIES_DEPTH,
+        )?);
+        path.push(sha256::length_chunk(summaries.len()));
+        path.extend(proven_state.field_path(HISTORICAL_SUMMARIES_FIELD_INDEX)?);
+
+        Ok(HistoricalBlockProof {
+            slot,
+            block_root,
+            source: HistoricalRootSource::HistoricalSummaries,
+            path,
+        })
+    }
+
+    /// Verifies the block root against a proven beacon state root
+    ///
+    /// # Arguments
+    /// * `state_root` - The proven `beacon_state_root`
+    /// * `state_slot` - The slot of the proven beacon header
+    pub fn verify(
+        &self,
+        state_root: FixedBytes<32>,
+        state_slot: u64,
+    ) -> Result<(), BeaconStateProofError> {
+        let vector_index = self.slot % SLOTS_PER_HISTORICAL_ROOT;
+
+        match self.source {
+            HistoricalRootSource::BlockRoots => {
+                if !is_in_block_roots(self.slot, state_slot) {
+                    return Err(BeaconStateProofError::InvalidProof(format!(
+                        "Slot {} is not part of the block roots of slot {}",
+                        self.slot, state_slot
+                    )));
+                }
+
+                verify_state_path(
+                    &self.path,
+                    self.block_root,
+                    vector_index,
+                    StateField::vector(BLOCK_ROOTS_FIELD_INDEX, BLOCK_ROOTS_DEPTH),
+                    state_slot,
+                    state_root,
+                )
+            }
+            HistoricalRootSource::HistoricalSummaries => {
+                if self.slot < CAPELLA_FORK_SLOT {
+                    return Err(BeaconStateProofError::InvalidProof(format!(
+                        "Slot {} predates historical summaries",
+                        self.slot
+                    )));
+                }
+
+                // The summary has to exist in the state, so its index must be below the list length
+                let length_index = BLOCK_ROOTS_DEPTH + 1 + HISTORICAL_SUMMARIES_DEPTH;
+                let length = self
+                    .path
+                    .get(length_index)
+                    .map(|chunk| u64::from_le_bytes(chunk[0..8].try_into().unwrap()))
+                    .unwrap_or_default();
+                let summary_index = historical_summary_index(self.slot);
+                if summary_index >= length {
+                    return Err(BeaconStateProofError::IndexOutOfBounds {
+                        index: summary_index,
+                        len: length as usize,
+                    });
+                }
+
+                // The block summary root is the left child of the summary container
+                let index = (summary_index << (BLOCK_ROOTS_DEPTH + 1)) | vector_index;
+                verify_state_path(
+                    &self.path,
+                    self.block_root,
+                    index,
+                    StateField::list(HISTORICAL_SUMMARIES_FIELD_INDEX, length_index),
+                    state_slot,
+                    state_root,
+                )
+            }
+        }
+    }
+}
+
+/// Returns the root of a slot in the `block_roots` of a state and its path to the vector root
+fn block_roots_path(
+    state: &BeaconStateProofBuilder,
+    slot: u64,
+) -> Result<(FixedBytes<32>, Vec<FixedBytes<32>>), BeaconStateProofError> {
+    let block_roots: Vec<FixedBytes<32>> = state
+        .state()
+        .block_roots()
+        .iter()
+        .map(|root| FixedBytes::from_slice(root.as_slice()))
+        .collect();
+
+    let index = (slot % SLOTS_PER_HISTORICAL_ROOT) as usize;
+    let path = sha256::generate_path_with_depth(&block_roots, index, BLOCK_ROOTS_DEPTH)?;
+
+    Ok((block_roots[index], path))
+}
+
+/// Returns true if the block root of `slot` is still stored in the `block_roots` at `state_slot`
+fn is_in_block_roots(slot: u64, state_slot: u64) -> bool {
+    slot < state_slot && state_slot <= slot + SLOTS_PER_HISTORICAL_ROOT
+}
+
+/// Returns the index of the historical summary covering a slot
+fn historical_summary_index(slot: u64) -> u64 {
+    (slot - CAPELLA_FORK_SLOT) / SLOTS_PER_HISTORICAL_ROOT
+}
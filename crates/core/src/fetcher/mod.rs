@@ -1,3 +1,6 @@
+pub mod blob_commitment_proof;
 pub mod execution_header_input;
+pub mod historical_block_proof;
 pub mod recursive_epoch_input;
+pub mod state_proofs;
 pub mod sync_committee_input;
@@ -6,12 +6,12 @@
 
 use std::fs;
 
-use crate::clients::beacon_chain::BeaconRpcClient;
+use crate::clients::beacon_chain::{BeaconBlock, BeaconRpcClient};
 use crate::clients::ClientError;
 use crate::fetcher::execution_header_input::ExecutionHeaderError;
 use crate::fetcher::sync_committee_input::{SyncCommitteeData, SyncCommitteeValidatorPubs};
-use crate::utils::constants;
 use crate::utils::hashing::get_committee_hash;
+use crate::utils::{constants, helpers};
 use crate::{
     clients::beacon_chain::BeaconError, fetcher::execution_header_input::ExecutionHeaderProof,
 };
@@ -21,6 +21,7 @@ use alloy_rpc_types_beacon::{
     events::light_client_finality::SyncAggregate, header::HeaderResponse,
 };
 use bls12_381::{G1Affine, G2Affine};
+use futures::TryFutureExt;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::{debug, error, info};
@@ -36,7 +37,7 @@ pub struct RecursiveEpochUpdate {
     pub outputs: RecursiveEpochOutput,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RecursiveEpochOutput {
     pub beacon_header_root: FixedBytes<32>,
     pub beacon_state_root: FixedBytes<32>,
@@ -108,13 +109,15 @@ impl From<RecursiveEpochInputs> for RecursiveEpochOutput {
             .execution_header_proof
             .execution_payload_header
             .block_hash();
-        println!("committee update: {:?}", val.sync_committee_update);
-
-        println!("beacon slot: {:?}", val.epoch_update.header.slot);
+        debug!(
+            "Computing outputs for slot {} (committee update: {})",
+            val.epoch_update.header.slot,
+            val.sync_committee_update.is_some()
+        );
 
         let (current_committee_hash, next_committee_hash) = if val.stark_proof_output.is_some() {
-            if (val.epoch_update.header.slot + 1) % 8192 != 0 {
-                println!("No sync committee transition");
+            let previous_slot = val.stark_proof_output.as_ref().unwrap().beacon_height;
+            if !helpers::is_committee_transition(previous_slot, val.epoch_update.header.slot) {
                 match val.sync_committee_update {
                     None => (
                         val.stark_proof_output
@@ -137,7 +140,10 @@ impl From<RecursiveEpochInputs> for RecursiveEpochOutput {
                     ),
                 }
             } else {
-                println!("Sync committee transition");
+                debug!(
+                    "Sync committee transition at slot {}",
+                    val.epoch_update.header.slot
+                );
                 (
                     val.stark_proof_output.as_ref().unwrap().next_committee_hash,
                     FixedBytes::from([0u8; 32]),
@@ -150,7 +156,6 @@ impl From<RecursiveEpochInputs> for RecursiveEpochOutput {
             )
         };
 
-        println!("next_committee_hash: {:?}", next_committee_hash);
         let out = RecursiveEpochOutput {
             beacon_header_root: val.epoch_update.header.tree_hash_root(),
             beacon_state_root: val.epoch_update.header.state_root,
@@ -165,7 +170,7 @@ impl From<RecursiveEpochInputs> for RecursiveEpochOutput {
             current_committee_hash,
             next_committee_hash,
         };
-        println!("RecursiveEpochOutput: {:?}", out);
+        debug!("RecursiveEpochOutput: {:?}", out);
         out
     }
 }
@@ -181,7 +186,7 @@ impl RecursiveEpochInputs {
     /// * `Result<Self, EpochUpdateError>` - New epoch update or error
     pub async fn new(
         client: &BeaconRpcClient,
-        db: &crate::db::Database,
+        db: &dyn crate::db::EpochStore,
         fast_forward: Option<u64>,
     ) -> Result<Self, EpochUpdateError> {
         info!("🔍 Initializing recursive epoch inputs...");
@@ -248,8 +253,10 @@ impl RecursiveEpochInputs {
                     Some(ref output) => {
                         if output.next_committee_hash == FixedBytes::from([0u8; 32]) {
                             info!("🔄 Next committee hash is zero, generating sync committee update...");
+                            // The committee is decommitted from the state root of the header
+                            // actually used, which can differ from the target slot
                             let sync_committee_update =
-                                SyncCommitteeData::new(client, slot).await?;
+                                SyncCommitteeData::new(client, epoch_update.header.slot).await?;
                             info!("✅ Sync committee update generated");
                             Some(sync_committee_update)
                         } else {
@@ -293,6 +300,16 @@ impl RecursiveEpochInputs {
         }
     }
 
+    /// Returns the timestamp of the execution payload header proven by the update
+    ///
+    /// It is not part of the outputs, so it is passed to `EpochStore::create_epoch_update`.
+    pub fn execution_timestamp(&self) -> u64 {
+        self.epoch_update
+            .execution_header_proof
+            .execution_payload_header
+            .timestamp()
+    }
+
     /// Loads recursive epoch inputs from JSON file
     ///
     /// # Arguments
@@ -319,77 +336,47 @@ impl EpochUpdate {
     /// * `Result<EpochUpdate, EpochUpdateError>` - Generated inputs or error
     pub(crate) async fn generate_epoch_proof(
         client: &BeaconRpcClient,
-        mut slot: u64,
+        slot: u64,
     ) -> Result<EpochUpdate, EpochUpdateError> {
         info!("🏗️  Starting epoch proof generation for slot {}", slot);
-        let mut attempts = 0;
-        let original_slot = slot;
-
-        info!("📥 Fetching beacon header...");
-        let header = loop {
-            debug!(
-                "🔍 Attempting to fetch header for slot {} (attempt {})",
-                slot,
-                attempts + 1
-            );
-            match client.get_header(slot).await {
-                Ok(header) => {
-                    info!("✅ Successfully fetched header for slot {}", slot);
-                    if slot != original_slot {
-                        info!(
-                            "ℹ️  Note: Skipped {} empty slots (from {} to {})",
-                            slot - original_slot,
-                            original_slot,
-                            slot
-                        );
-                    }
-                    break header;
-                }
-                Err(BeaconError::EmptySlot(_)) => {
-                    attempts += 1;
-                    if attempts >= constants::MAX_SKIPPED_SLOTS_RETRY_ATTEMPTS {
-                        let _error_msg = format!(
-                            "Exceeded maximum empty slot retry attempts ({}) starting from slot {}",
-                            constants::MAX_SKIPPED_SLOTS_RETRY_ATTEMPTS,
-                            original_slot
-                        );
-                        return Err(EpochUpdateError::Client(
-                            BeaconError::EmptySlot(slot).into(),
-                        ));
-                    }
-                    slot += 1;
-                    debug!(
-                        "⚠️  Empty slot detected at {}! Attempt {}/{}. Trying next slot: {}",
-                        slot - 1,
-                        attempts,
-                        constants::MAX_SKIPPED_SLOTS_RETRY_ATTEMPTS,
-                        slot
-                    );
-                }
-                Err(e) => {
-                    error!("❌ Failed to fetch header for slot {}: {}", slot, e);
-                    return Err(EpochUpdateError::Client(e.into()));
-                }
-            }
-        };
 
-        info!("📥 Fetching sync aggregate for slot {}...", slot);
-        let sync_agg = client
-            .get_sync_aggregate(slot)
-            .await
-            .map_err(ClientError::Beacon)?;
-        info!("✅ Sync aggregate fetched successfully");
+        info!("📥 Fetching beacon block...");
+        let block = Self::fetch_block_for_slot(client, slot).await?;
+        let slot = block.slot;
 
-        info!("📥 Fetching sync committee validator public keys...");
-        let validator_pubs = client
-            .get_sync_committee_validator_pubs(slot)
-            .await
-            .map_err(ClientError::Beacon)?;
+        // The signing block and the committee keys are independent of each other, so fetch them concurrently
         info!(
-            "✅ Validator public keys fetched successfully ({} validators)",
+            "📥 Fetching sync aggregate and sync committee validator public keys for slot {}...",
+            slot
+        );
+        let ((signature_slot, sync_agg), validator_pubs) = tokio::try_join!(
+            client.get_sync_aggregate(slot).map_err(ClientError::Beacon),
+            client
+                .get_sync_committee_validator_pubs(slot)
+                .map_err(ClientError::Beacon),
+        )?;
+        info!(
+            "✅ Sync aggregate (signature slot {}) and validator public keys fetched successfully ({} validators)",
+            signature_slot,
             validator_pubs.validator_pubs.len()
         );
 
+        // The circuit derives the signing committee from `slot + 1`, which is also the state the
+        // validator keys were fetched from. If empty slots pushed the signature into another
+        // sync committee period, the aggregate was signed by a committee the circuit can't select.
+        if helpers::get_sync_committee_id_by_slot(signature_slot)
+            != helpers::get_sync_committee_id_by_slot(slot + 1)
+        {
+            error!(
+                "❌ Signature slot {} is in a different sync committee period than header slot {}",
+                signature_slot, slot
+            );
+            return Err(EpochUpdateError::CommitteePeriodMismatch {
+                header_slot: slot,
+                signature_slot,
+            });
+        }
+
         info!("🔐 Processing BLS signature...");
         let signature_point = Self::extract_signature_point(&sync_agg)?;
         info!("✅ BLS signature point extracted successfully");
@@ -403,16 +390,16 @@ impl EpochUpdate {
             non_signers.len()
         );
 
-        info!("📋 Fetching execution header proof...");
-        let execution_header_proof = ExecutionHeaderProof::fetch_proof(client, slot).await?;
-        info!("✅ Execution header proof fetched successfully");
+        info!("📋 Building execution header proof...");
+        let execution_header_proof = ExecutionHeaderProof::from_block_body(&block.body, slot)?;
+        info!("✅ Execution header proof built successfully");
 
         info!(
             "🎉 Epoch proof generation completed successfully for slot {}",
             slot
         );
         Ok(EpochUpdate {
-            header: header.into(),
+            header: BeaconHeader::from(&block),
             signature_point,
             aggregate_pub: G1Point(validator_pubs.aggregate_pub),
             non_signers: non_signers.iter().map(|p| G1Point(*p)).collect(),
@@ -420,6 +407,60 @@ impl EpochUpdate {
         })
     }
 
+    /// Fetches the block for the target slot, resolving empty slots with the configured policy
+    ///
+    /// The slots are tried in the order of `EmptySlotPolicy::candidate_slots`.
+    ///
+    /// # Arguments
+    /// * `client` - Reference to the beacon chain client
+    /// * `target_slot` - Slot the epoch update should be generated for
+    ///
+    /// # Returns
+    /// * `Result<BeaconBlock, EpochUpdateError>` - The block actually used or error
+    async fn fetch_block_for_slot(
+        client: &BeaconRpcClient,
+        target_slot: u64,
+    ) -> Result<BeaconBlock, EpochUpdateError> {
+        let policy = client.config.empty_slot_policy;
+        let candidates = policy.candidate_slots(target_slot);
+
+        let mut last_slot = target_slot;
+        for slot in candidates.iter().copied() {
+            debug!("🔍 Attempting to fetch block for slot {}", slot);
+            match client.get_block(slot).await {
+                Ok(block) => {
+                    info!("✅ Successfully fetched block for slot {}", slot);
+                    if slot != target_slot {
+                        info!(
+                            "ℹ️  Note: Skipped {} empty slots (from {} to {}, {:?})",
+                            slot.abs_diff(target_slot),
+                            target_slot,
+                            slot,
+                            policy
+                        );
+                    }
+                    return Ok(block);
+                }
+                Err(BeaconError::EmptySlot(_)) => {
+                    debug!("⚠️  Empty slot detected at {}! ({:?})", slot, policy);
+                    last_slot = slot;
+                }
+                Err(e) => {
+                    error!("❌ Failed to fetch block for slot {}: {}", slot, e);
+                    return Err(EpochUpdateError::Client(e.into()));
+                }
+            }
+        }
+
+        error!(
+            "❌ No non-empty slot found in {:?} for target slot {}",
+            candidates, target_slot
+        );
+        Err(EpochUpdateError::Client(
+            BeaconError::EmptySlot(last_slot).into(),
+        ))
+    }
+
     /// Extracts and validates the BLS signature point from the sync aggregate
     ///
     /// # Arguments
@@ -471,6 +512,21 @@ impl EpochUpdate {
     }
 }
 
+impl From<&BeaconBlock> for BeaconHeader {
+    fn from(block: &BeaconBlock) -> Self {
+        Self {
+            slot: block.slot,
+            proposer_index: block.proposer_index,
+            parent_root: block.parent_root,
+            state_root: block.state_root,
+            body_root: FixedBytes::from_slice(
+                beacon_state_proof::state_proof_fetcher::TreeHash::tree_hash_root(&block.body)
+                    .as_slice(),
+            ),
+        }
+    }
+}
+
 impl From<HeaderResponse> for BeaconHeader {
     fn from(header: HeaderResponse) -> Self {
         Self {
@@ -541,97 +597,135 @@ impl Serialize for G2Point {
     }
 }
 
+/// JSON encodings accepted for a G1 point
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum G1PointEncoding {
+    /// Hex encoded compressed (48 bytes) or uncompressed (96 bytes) point
+    Bytes(String),
+    /// Hex encoded affine coordinates
+    Coordinates { x: String, y: String },
+}
+
+/// JSON encodings accepted for a G2 point
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum G2PointEncoding {
+    /// Hex encoded compressed (96 bytes) or uncompressed (192 bytes) point
+    Bytes(String),
+    /// Hex encoded affine coordinates, `x = x0 + x1 * u` and `y = y0 + y1 * u`
+    Coordinates {
+        x0: String,
+        x1: String,
+        y0: String,
+        y1: String,
+    },
+}
+
+/// Decodes a hex string into a fixed size array
+fn decode_hex<const N: usize, E: serde::de::Error>(name: &str, value: &str) -> Result<[u8; N], E> {
+    let bytes = hex::decode(value.strip_prefix("0x").unwrap_or(value))
+        .map_err(|e| E::custom(format!("invalid {} hex: {}", name, e)))?;
+    bytes.try_into().map_err(|bytes: Vec<u8>| {
+        E::custom(format!(
+            "invalid {} length: expected {} bytes, got {}",
+            name,
+            N,
+            bytes.len()
+        ))
+    })
+}
+
+/// Rejects points that are not on the curve or not in the prime order subgroup
+fn check_point<E: serde::de::Error>(on_curve: bool, torsion_free: bool) -> Result<(), E> {
+    if !on_curve {
+        return Err(E::custom("point is not on the curve"));
+    }
+    if !torsion_free {
+        return Err(E::custom("point is not in the prime order subgroup"));
+    }
+    Ok(())
+}
+
 impl<'de> Deserialize<'de> for G1Point {
-    /// Deserializes a G1 point from its uncompressed form
+    /// Deserializes a G1 point from its coordinates or its compressed or uncompressed encoding
     ///
-    /// Expects x and y coordinates as hex strings
+    /// Points that are not on the curve or not in the subgroup are rejected.
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        // Deserialize into a Value first
-        let value: serde_json::Value = serde_json::Value::deserialize(deserializer)?;
-
-        // Extract x and y coordinates
-        let x_str = value["x"]
-            .as_str()
-            .ok_or_else(|| serde::de::Error::custom("missing x coordinate"))?;
-        let y_str = value["y"]
-            .as_str()
-            .ok_or_else(|| serde::de::Error::custom("missing y coordinate"))?;
-
-        // Safely remove "0x" prefix if it exists
-        let x_hex = x_str.strip_prefix("0x").unwrap_or(x_str);
-        let y_hex = y_str.strip_prefix("0x").unwrap_or(y_str);
-
-        let x_bytes = hex::decode(x_hex)
-            .map_err(|e| serde::de::Error::custom(format!("invalid x hex: {}", e)))?;
-        let y_bytes = hex::decode(y_hex)
-            .map_err(|e| serde::de::Error::custom(format!("invalid y hex: {}", e)))?;
-
-        // Combine into uncompressed format
-        let mut uncompressed = [0u8; 96];
-        uncompressed[0..48].copy_from_slice(&x_bytes);
-        uncompressed[48..96].copy_from_slice(&y_bytes);
-
-        // Convert to G1Affine point
-        let point = G1Affine::from_uncompressed(&uncompressed).unwrap();
+        let point = match G1PointEncoding::deserialize(deserializer)? {
+            G1PointEncoding::Bytes(encoded) => {
+                let hex = encoded.strip_prefix("0x").unwrap_or(&encoded);
+                match hex.len() / 2 {
+                    48 => {
+                        let bytes = decode_hex::<48, D::Error>("point", hex)?;
+                        Option::from(G1Affine::from_compressed_unchecked(&bytes)).ok_or_else(
+                            || serde::de::Error::custom("invalid compressed G1 point"),
+                        )?
+                    }
+                    _ => {
+                        let bytes = decode_hex::<96, D::Error>("point", hex)?;
+                        Option::from(G1Affine::from_uncompressed_unchecked(&bytes)).ok_or_else(
+                            || serde::de::Error::custom("invalid uncompressed G1 point"),
+                        )?
+                    }
+                }
+            }
+            G1PointEncoding::Coordinates { x, y } => {
+                let mut uncompressed = [0u8; 96];
+                uncompressed[0..48].copy_from_slice(&decode_hex::<48, D::Error>("x", &x)?);
+                uncompressed[48..96].copy_from_slice(&decode_hex::<48, D::Error>("y", &y)?);
+                Option::from(G1Affine::from_uncompressed_unchecked(&uncompressed))
+                    .ok_or_else(|| serde::de::Error::custom("invalid G1 coordinates"))?
+            }
+        };
 
+        check_point(point.is_on_curve().into(), point.is_torsion_free().into())?;
         Ok(G1Point(point))
     }
 }
 
 impl<'de> Deserialize<'de> for G2Point {
-    /// Deserializes a G2 point from its uncompressed form
+    /// Deserializes a G2 point from its coordinates or its compressed or uncompressed encoding
     ///
-    /// Expects x0, x1, y0, y1 coordinates as hex strings
+    /// Points that are not on the curve or not in the subgroup are rejected.
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        // Deserialize into a Value first
-        let value: serde_json::Value = serde_json::Value::deserialize(deserializer)?;
-
-        // Extract coordinates
-        let x0_str = value["x0"]
-            .as_str()
-            .ok_or_else(|| serde::de::Error::custom("missing x0 coordinate"))?;
-        let x1_str = value["x1"]
-            .as_str()
-            .ok_or_else(|| serde::de::Error::custom("missing x1 coordinate"))?;
-        let y0_str = value["y0"]
-            .as_str()
-            .ok_or_else(|| serde::de::Error::custom("missing y0 coordinate"))?;
-        let y1_str = value["y1"]
-            .as_str()
-            .ok_or_else(|| serde::de::Error::custom("missing y1 coordinate"))?;
-
-        // Safely remove "0x" prefix if it exists
-        let x0_hex = x0_str.strip_prefix("0x").unwrap_or(x0_str);
-        let x1_hex = x1_str.strip_prefix("0x").unwrap_or(x1_str);
-        let y0_hex = y0_str.strip_prefix("0x").unwrap_or(y0_str);
-        let y1_hex = y1_str.strip_prefix("0x").unwrap_or(y1_str);
-
-        // Decode hex strings to bytes
-        let x0_bytes = hex::decode(x0_hex)
-            .map_err(|e| serde::de::Error::custom(format!("invalid x0 hex: {}", e)))?;
-        let x1_bytes = hex::decode(x1_hex)
-            .map_err(|e| serde::de::Error::custom(format!("invalid x1 hex: {}", e)))?;
-        let y0_bytes = hex::decode(y0_hex)
-            .map_err(|e| serde::de::Error::custom(format!("invalid y0 hex: {}", e)))?;
-        let y1_bytes = hex::decode(y1_hex)
-            .map_err(|e| serde::de::Error::custom(format!("invalid y1 hex: {}", e)))?;
-
-        // Combine into uncompressed format
-        let mut uncompressed = [0u8; 192];
-        uncompressed[0..48].copy_from_slice(&x1_bytes);
-        uncompressed[48..96].copy_from_slice(&x0_bytes);
-        uncompressed[96..144].copy_from_slice(&y1_bytes);
-        uncompressed[144..192].copy_from_slice(&y0_bytes);
-
-        // Convert to G2Affine point
-        let point = G2Affine::from_uncompressed(&uncompressed).unwrap();
+        let point = match G2PointEncoding::deserialize(deserializer)? {
+            G2PointEncoding::Bytes(encoded) => {
+                let hex = encoded.strip_prefix("0x").unwrap_or(&encoded);
+                match hex.len() / 2 {
+                    96 => {
+                        let bytes = decode_hex::<96, D::Error>("point", hex)?;
+                        Option::from(G2Affine::from_compressed_unchecked(&bytes)).ok_or_else(
+                            || serde::de::Error::custom("invalid compressed G2 point"),
+                        )?
+                    }
+                    _ => {
+                        let bytes = decode_hex::<192, D::Error>("point", hex)?;
+                        Option::from(G2Affine::from_uncompressed_unchecked(&bytes)).ok_or_else(
+                            || serde::de::Error::custom("invalid uncompressed G2 point"),
+                        )?
+                    }
+                }
+            }
+            G2PointEncoding::Coordinates { x0, x1, y0, y1 } => {
+                // The uncompressed encoding orders the coefficients as x1, x0, y1, y0
+                let mut uncompressed = [0u8; 192];
+                uncompressed[0..48].copy_from_slice(&decode_hex::<48, D::Error>("x1", &x1)?);
+                uncompressed[48..96].copy_from_slice(&decode_hex::<48, D::Error>("x0", &x0)?);
+                uncompressed[96..144].copy_from_slice(&decode_hex::<48, D::Error>("y1", &y1)?);
+                uncompressed[144..192].copy_from_slice(&decode_hex::<48, D::Error>("y0", &y0)?);
+                Option::from(G2Affine::from_uncompressed_unchecked(&uncompressed))
+                    .ok_or_else(|| serde::de::Error::custom("invalid G2 coordinates"))?
+            }
+        };
 
+        check_point(point.is_on_curve().into(), point.is_torsion_free().into())?;
         Ok(G2Point(point))
     }
 }
@@ -657,4 +751,10 @@ pub enum EpochUpdateError {
     /// Invalid BLS cryptographic point
     #[error("Invalid BLS point")]
     InvalidBLSPoint,
+    /// The signature for the header was produced in a different sync committee period
+    #[error("Signature slot {signature_slot} is in a different sync committee period than header slot {header_slot}")]
+    CommitteePeriodMismatch {
+        header_slot: u64,
+        signature_slot: u64,
+    },
 }
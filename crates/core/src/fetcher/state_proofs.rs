@@ -0,0 +1,537 @@
+use alloy_primitives::FixedBytes;
+use beacon_state_proof::state_proof_fetcher::TreeHash;
+use beacon_types::{BeaconState, MainnetEthSpec, Validator};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::clients::beacon_chain::{BeaconError, BeaconRpcClient};
+use crate::utils::constants::ELECTRA_FORK_SLOT;
+use crate::utils::merkle::{sha256, MerkleError};
+
+/// Field index of `validators` in the `BeaconState` container
+const VALIDATORS_FIELD_INDEX: u64 = 11;
+/// Field index of `balances` in the `BeaconState` container
+const BALANCES_FIELD_INDEX: u64 = 12;
+/// Field index of `randao_mixes` in the `BeaconState` container
+const RANDAO_MIXES_FIELD_INDEX: u64 = 13;
+/// Field index of `finalized_checkpoint` in the `BeaconState` container
+const FINALIZED_CHECKPOINT_FIELD_INDEX: u64 = 20;
+
+/// Depth of the validator registry tree (`VALIDATOR_REGISTRY_LIMIT = 2^40`)
+const VALIDATORS_DEPTH: usize = 40;
+/// Depth of the balances tree (`VALIDATOR_REGISTRY_LIMIT` balances packed 4 per chunk)
+const BALANCES_DEPTH: usize = 38;
+/// Depth of the randao mixes vector (`EPOCHS_PER_HISTORICAL_VECTOR = 2^16`)
+const RANDAO_MIXES_DEPTH: usize = 16;
+/// Number of randao mixes stored in the state
+pub const EPOCHS_PER_HISTORICAL_VECTOR: u64 = 1 << RANDAO_MIXES_DEPTH;
+/// Depth of the `BeaconState` container tree before Electra, with at most 28 fields
+const STATE_DEPTH: usize = 5;
+/// Depth of the `BeaconState` container tree from Electra, with 37 fields
+const ELECTRA_STATE_DEPTH: usize = 6;
+
+/// Builds SSZ proofs for values of a beacon state whose root has been proven by Bankai
+///
+/// The full state is fetched once, so multiple proofs can be generated from a single builder.
+#[derive(Debug, Clone)]
+pub struct BeaconStateProofBuilder {
+    /// Slot of the beacon state
+    pub slot: u64,
+    /// Root of the beacon state
+    pub state_root: FixedBytes<32>,
+    field_roots: Vec<FixedBytes<32>>,
+    state: BeaconState<MainnetEthSpec>,
+}
+
+impl BeaconStateProofBuilder {
+    /// Fetches the beacon state of a slot and ensures it matches the proven state root
+    ///
+    /// # Arguments
+    /// * `client` - Reference to the beacon node RPC client
+    /// * `slot` - The slot of the proven beacon header
+    /// * `state_root` - The proven `beacon_state_root` of that header
+    ///
+    /// # Returns
+    /// * `Result<BeaconStateProofBuilder, BeaconStateProofError>` - The builder or an error
+    pub async fn fetch(
+        client: &BeaconRpcClient,
+        slot: u64,
+        state_root: FixedBytes<32>,
+    ) -> Result<Self, BeaconStateProofError> {
+        let state = client.get_state(slot).await?;
+        let builder = Self::from_state(state, slot)?;
+
+        if builder.state_root != state_root {
+            return Err(BeaconStateProofError::StateRootMismatch {
+                expected: state_root,
+                actual: builder.state_root,
+            });
+        }
+
+        Ok(builder)
+    }
+
+    /// Creates the builder from an already fetched beacon state
+    ///
+    /// # Arguments
+    /// * `state` - The beacon state
+    /// * `slot` - The slot of the beacon state
+    pub fn from_state(
+        state: BeaconState<MainnetEthSpec>,
+        slot: u64,
+    ) -> Result<Self, BeaconStateProofError> {
+        let field_roots: Vec<FixedBytes<32>> = state
+            .get_beacon_state_leaves()
+            .into_iter()
+            .map(|leaf| FixedBytes::from_slice(leaf.as_slice()))
+            .collect();
+        let state_root = sha256::merkleize(&field_roots, state_depth(slot))?;
+
+        Ok(Self {
+            slot,
+            state_root,
+            field_roots,
+            state,
+        })
+    }
+
+    /// Generates inclusion proofs for a set of validator records
+    ///
+    /// # Arguments
+    /// * `indexes` - The validator indexes to prove
+    ///
+    /// # Returns
+    /// * `Result<Vec<ValidatorProof>, BeaconStateProofError>` - The proofs in request order
+    pub fn validator_proofs(
+        &self,
+        indexes: &[u64],
+    ) -> Result<Vec<ValidatorProof>, BeaconStateProofError> {
+        let validators = self.state.validators();
+        let leaves: Vec<FixedBytes<32>> = validators
+            .iter()
+            .map(|validator| FixedBytes::from_slice(validator.tree_hash_root().as_slice()))
+            .collect();
+
+        indexes
+            .iter()
+            .map(|&index| {
+                let validator = validators.get(index as usize).ok_or(
+                    BeaconStateProofError::IndexOutOfBounds {
+                        index,
+                        len: leaves.len(),
+                    },
+                )?;
+
+                Ok(ValidatorProof {
+                    slot: self.slot,
+                    validator_index: index,
+                    validator: validator.clone(),
+                    path: self.list_path(
+                        &leaves,
+                        index,
+                        VALIDATORS_DEPTH,
+                        VALIDATORS_FIELD_INDEX,
+                    )?,
+                })
+            })
+            .collect()
+    }
+
+    /// Generates inclusion proofs for a set of validator balances
+    ///
+    /// # Arguments
+    /// * `indexes` - The validator indexes to prove the balances of
+    ///
+    /// # Returns
+    /// * `Result<Vec<BalanceProof>, BeaconStateProofError>` - The proofs in request order
+    pub fn balance_proofs(
+        &self,
+        indexes: &[u64],
+    ) -> Result<Vec<BalanceProof>, BeaconStateProofError> {
+        let balances = self.state.balances();
+        let bytes: Vec<u8> = balances
+            .iter()
+            .flat_map(|balance| balance.to_le_bytes())
+            .collect();
+        let chunks = sha256::pack_bytes(&bytes);
+
+        indexes
+            .iter()
+            .map(|&index| {
+                let balance = balances.get(index as usize).ok_or(
+                    BeaconStateProofError::IndexOutOfBounds {
+                        index,
+                        len: balances.len(),
+                    },
+                )?;
+
+                // The list length mixed into the root is the number of balances, not chunks
+                let mut path = sha256::generate_path_with_depth(
+                    &chunks,
+                    (index / 4) as usize,
+                    BALANCES_DEPTH,
+                )?;
+                path.push(sha256::length_chunk(balances.len()));
+                path.extend(self.field_path(BALANCES_FIELD_INDEX)?);
+
+                Ok(BalanceProof {
+                    slot: self.slot,
+                    validator_index: index,
+                    balance: *balance,
+                    chunk: chunks[(index / 4) as usize],
+                    path,
+                })
+            })
+            .collect()
+    }
+
+    /// Generates the inclusion proof of the randao mix stored for an epoch
+    ///
+    /// # Arguments
+    /// * `epoch` - The epoch of the randao mix, stored at `epoch % EPOCHS_PER_HISTORICAL_VECTOR`
+    pub fn randao_mix_proof(&self, epoch: u64) -> Result<RandaoMixProof, BeaconStateProofError> {
+        let leaves: Vec<FixedBytes<32>> = self
+            .state
+            .randao_mixes()
+            .iter()
+            .map(|mix| FixedBytes::from_slice(mix.as_slice()))
+            .collect();
+
+        let index = epoch % EPOCHS_PER_HISTORICAL_VECTOR;
+        let mut path =
+            sha256::generate_path_with_depth(&leaves, index as usize, RANDAO_MIXES_DEPTH)?;
+        path.extend(self.field_path(RANDAO_MIXES_FIELD_INDEX)?);
+
+        Ok(RandaoMixProof {
+            slot: self.slot,
+            epoch,
+            mix: leaves[index as usize],
+            path,
+        })
+    }
+
+    /// Generates the inclusion proof of the finalized checkpoint of the state
+    pub fn finalized_checkpoint_proof(
+        &self,
+    ) -> Result<FinalizedCheckpointProof, BeaconStateProofError> {
+        let checkpoint = self.state.finalized_checkpoint();
+
+        Ok(FinalizedCheckpointProof {
+            slot: self.slot,
+            epoch: checkpoint.epoch.as_u64(),
+            root: FixedBytes::from_slice(checkpoint.root.as_slice()),
+            path: self.field_path(FINALIZED_CHECKPOINT_FIELD_INDEX)?,
+        })
+    }
+
+    /// Returns the path from a list element to the state root
+    fn list_path(
+        &self,
+        leaves: &[FixedBytes<32>],
+        index: u64,
+        depth: usize,
+        field_index: u64,
+    ) -> Result<Vec<FixedBytes<32>>, BeaconStateProofError> {
+        let mut path = sha256::generate_path_with_depth(leaves, index as usize, depth)?;
+        path.push(sha256::length_chunk(leaves.len()));
+        path.extend(self.field_path(field_index)?);
+        Ok(path)
+    }
+
+    /// Returns the underlying beacon state
+    pub(crate) fn state(&self) -> &BeaconState<MainnetEthSpec> {
+        &self.state
+    }
+
+    /// Returns the root of a state field
+    pub(crate) fn field_root(&self, field_index: u64) -> Option<FixedBytes<32>> {
+        self.field_roots.get(field_index as usize).copied()
+    }
+
+    /// Returns the path from a state field to the state root
+    pub(crate) fn field_path(
+        &self,
+        field_index: u64,
+    ) -> Result<Vec<FixedBytes<32>>, BeaconStateProofError> {
+        let path = sha256::generate_path_with_depth(
+            &self.field_roots,
+            field_index as usize,
+            state_depth(self.slot),
+        )?;
+        Ok(path)
+    }
+}
+
+/// Proof of a validator record in the `validators` list of a beacon state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidatorProof {
+    /// Slot of the beacon state
+    pub slot: u64,
+    /// Index of the validator in the registry
+    pub validator_index: u64,
+    /// The validator record
+    pub validator: Validator,
+    /// Merkle path from the validator root to the state root
+    pub path: Vec<FixedBytes<32>>,
+}
+
+impl ValidatorProof {
+    /// Verifies the validator record against a proven beacon state root
+    ///
+    /// # Arguments
+    /// * `state_root` - The proven `beacon_state_root`
+    /// * `state_slot` - The slot of the proven beacon header
+    pub fn verify(
+        &self,
+        state_root: FixedBytes<32>,
+        state_slot: u64,
+    ) -> Result<(), BeaconStateProofError> {
+        check_slot(self.slot, state_slot)?;
+        let leaf = FixedBytes::from_slice(self.validator.tree_hash_root().as_slice());
+        verify_state_path(
+            &self.path,
+            leaf,
+            self.validator_index,
+            StateField::list(VALIDATORS_FIELD_INDEX, VALIDATORS_DEPTH),
+            state_slot,
+            state_root,
+        )
+    }
+}
+
+/// Proof of a validator balance in the `balances` list of a beacon state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceProof {
+    /// Slot of the beacon state
+    pub slot: u64,
+    /// Index of the validator in the registry
+    pub validator_index: u64,
+    /// Balance of the validator in Gwei
+    pub balance: u64,
+    /// The chunk of four packed balances containing the balance
+    pub chunk: FixedBytes<32>,
+    /// Merkle path from the chunk to the state root
+    pub path: Vec<FixedBytes<32>>,
+}
+
+impl BalanceProof {
+    /// Verifies the validator balance against a proven beacon state root
+    ///
+    /// # Arguments
+    /// * `state_root` - The proven `beacon_state_root`
+    /// * `state_slot` - The slot of the proven beacon header
+    pub fn verify(
+        &self,
+        state_root: FixedBytes<32>,
+        state_slot: u64,
+    ) -> Result<(), BeaconStateProofError> {
+        check_slot(self.slot, state_slot)?;
+        let offset = (self.validator_index % 4) as usize * 8;
+        if self.chunk[offset..offset + 8] != self.balance.to_le_bytes() {
+            return Err(BeaconStateProofError::InvalidProof(
+                "Balance is not part of the chunk".to_string(),
+            ));
+        }
+
+        verify_state_path(
+            &self.path,
+            self.chunk,
+            self.validator_index / 4,
+            StateField::list(BALANCES_FIELD_INDEX, BALANCES_DEPTH),
+            state_slot,
+            state_root,
+        )
+    }
+}
+
+/// Proof of an entry in the `randao_mixes` vector of a beacon state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RandaoMixProof {
+    /// Slot of the beacon state
+    pub slot: u64,
+    /// Epoch of the randao mix
+    pub epoch: u64,
+    /// The randao mix
+    pub mix: FixedBytes<32>,
+    /// Merkle path from the mix to the state root
+    pub path: Vec<FixedBytes<32>>,
+}
+
+impl RandaoMixProof {
+    /// Verifies the randao mix against a proven beacon state root
+    ///
+    /// # Arguments
+    /// * `state_root` - The proven `beacon_state_root`
+    /// * `state_slot` - The slot of the proven beacon header
+    pub fn verify(
+        &self,
+        state_root: FixedBytes<32>,
+        state_slot: u64,
+    ) -> Result<(), BeaconStateProofError> {
+        check_slot(self.slot, state_slot)?;
+        verify_state_path(
+            &self.path,
+            self.mix,
+            self.epoch % EPOCHS_PER_HISTORICAL_VECTOR,
+            StateField::vector(RANDAO_MIXES_FIELD_INDEX, RANDAO_MIXES_DEPTH),
+            state_slot,
+            state_root,
+        )
+    }
+}
+
+/// Proof of the `finalized_checkpoint` of a beacon state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinalizedCheckpointProof {
+    /// Slot of the beacon state
+    pub slot: u64,
+    /// Epoch of the finalized checkpoint
+    pub epoch: u64,
+    /// Block root of the finalized checkpoint
+    pub root: FixedBytes<32>,
+    /// Merkle path from the checkpoint root to the state root
+    pub path: Vec<FixedBytes<32>>,
+}
+
+impl FinalizedCheckpointProof {
+    /// Verifies the finalized checkpoint against a proven beacon state root
+    ///
+    /// # Arguments
+    /// * `state_root` - The proven `beacon_state_root`
+    /// * `state_slot` - The slot of the proven beacon header
+    pub fn verify(
+        &self,
+        state_root: FixedBytes<32>,
+        state_slot: u64,
+    ) -> Result<(), BeaconStateProofError> {
+        check_slot(self.slot, state_slot)?;
+        let epoch_root = FixedBytes::from_slice(self.epoch.tree_hash_root().as_slice());
+        let leaf = sha256::hash_pair(&epoch_root, &self.root);
+        verify_state_path(
+            &self.path,
+            leaf,
+            0,
+            StateField::vector(FINALIZED_CHECKPOINT_FIELD_INDEX, 0),
+            state_slot,
+            state_root,
+        )
+    }
+}
+
+/// Returns the depth of the `BeaconState` container tree at a slot
+///
+/// The depth changes with the number of state fields, so it is taken from the fork schedule
+/// rather than from the proof.
+pub fn state_depth(slot: u64) -> usize {
+    if slot >= ELECTRA_FORK_SLOT {
+        ELECTRA_STATE_DEPTH
+    } else {
+        STATE_DEPTH
+    }
+}
+
+/// Rejects proofs generated for another state than the proven one
+fn check_slot(slot: u64, state_slot: u64) -> Result<(), BeaconStateProofError> {
+    if slot != state_slot {
+        return Err(BeaconStateProofError::InvalidProof(format!(
+            "Proof of slot {} does not match the state at slot {}",
+            slot, state_slot
+        )));
+    }
+    Ok(())
+}
+
+/// Location of a field subtree in the `BeaconState` container
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StateField {
+    /// Index of the field in the state container
+    pub index: u64,
+    /// Depth of the subtree holding the leaves, without a mixed in list length
+    pub depth: usize,
+    /// Whether the list length is mixed in above the leaves
+    pub is_list: bool,
+}
+
+impl StateField {
+    /// A list field, whose root mixes in the list length
+    pub(crate) fn list(index: u64, depth: usize) -> Self {
+        Self {
+            index,
+            depth,
+            is_list: true,
+        }
+    }
+
+    /// A vector or container field
+    pub(crate) fn vector(index: u64, depth: usize) -> Self {
+        Self {
+            index,
+            depth,
+            is_list: false,
+        }
+    }
+}
+
+/// Verifies a path that first walks a field subtree and then the state container
+///
+/// # Arguments
+/// * `path` - The merkle path, ordered from the leaf up
+/// * `leaf` - The leaf value
+/// * `index` - The index of the leaf within the field subtree
+/// * `field` - The field the leaf is part of
+/// * `state_slot` - The slot of the proven state, which determines the container depth
+/// * `state_root` - The proven state root
+pub(crate) fn verify_state_path(
+    path: &[FixedBytes<32>],
+    leaf: FixedBytes<32>,
+    index: u64,
+    field: StateField,
+    state_slot: u64,
+    state_root: FixedBytes<32>,
+) -> Result<(), BeaconStateProofError> {
+    // The path length has to be fixed, otherwise the upper index bits would not be enforced.
+    // The index has to stay below the list limit, the next bit selects the length chunk.
+    let field_depth = field.depth + field.is_list as usize;
+    if path.len() != field_depth + state_depth(state_slot) || index >= 1 << field.depth {
+        return Err(BeaconStateProofError::InvalidProof(
+            "Unexpected path length".to_string(),
+        ));
+    }
+
+    let index = index | (field.index << field_depth);
+    let root = sha256::hash_path(path.to_vec(), leaf, index);
+    if root != state_root {
+        return Err(BeaconStateProofError::StateRootMismatch {
+            expected: state_root,
+            actual: root,
+        });
+    }
+
+    Ok(())
+}
+
+/// Possible errors that can occur while proving beacon state values
+#[derive(Debug, Error)]
+pub enum BeaconStateProofError {
+    /// Error communicating with beacon node
+    #[error("Beacon error: {0}")]
+    Beacon(#[from] BeaconError),
+    /// Error building the state merkle tree
+    #[error("Merkle error: {0}")]
+    Merkle(#[from] MerkleError),
+    /// The state or proof does not match the proven state root
+    #[error("State root mismatch: expected {expected}, got {actual}")]
+    StateRootMismatch {
+        expected: FixedBytes<32>,
+        actual: FixedBytes<32>,
+    },
+    /// The requested index is not part of the state
+    #[error("Index {index} out of bounds for list with {len} entries")]
+    IndexOutOfBounds { index: u64, len: usize },
+    /// Error accessing the beacon state
+    #[error("Beacon state error: {0:?}")]
+    BeaconState(beacon_types::Error),
+    /// The proof is malformed
+    #[error("Invalid proof: {0}")]
+    InvalidProof(String),
+}
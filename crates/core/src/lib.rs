@@ -2,13 +2,14 @@ use std::env;
 
 use crate::{
     clients::{atlantic::AtlanticClient, beacon_chain::BeaconRpcClient},
-    db::Database,
+    db::EpochStore,
     utils::config::BankaiConfig,
 };
 
 pub mod clients;
 pub mod conversion;
 pub mod db;
+pub mod execution;
 pub mod fetcher;
 pub mod utils;
 use dotenv::from_filename;
@@ -17,7 +18,7 @@ use dotenv::from_filename;
 pub struct BankaiClient {
     pub client: BeaconRpcClient,
     // pub config: BankaiConfig,
-    pub db: Database,
+    pub db: Box<dyn EpochStore>,
     pub atlantic_client: AtlanticClient,
 }
 
@@ -30,7 +31,7 @@ impl BankaiClient {
             BankaiConfig::default()
         };
 
-        let db = Database::new(&config.database_url)
+        let db = db::connect(&config.database_url, config.proof_blob_dir.as_deref())
             .await
             .expect("Failed to initialize database");
 
@@ -1,7 +1,53 @@
+use std::env;
+
+use crate::utils::{constants, helpers};
+
+/// Determines how an empty target slot is resolved when generating an epoch update
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EmptySlotPolicy {
+    /// Walk backwards to the last non-empty slot of the target epoch. The last slot of a sync
+    /// committee period is resolved forward, see `candidate_slots`
+    #[default]
+    Backward,
+    /// Walk forward, bounded by the retry limit
+    Forward,
+}
+
+impl EmptySlotPolicy {
+    /// Returns the slots to try for a target slot, in the order they are tried
+    ///
+    /// The signing block of an update is the first non-empty block after the slot used, while the
+    /// circuit selects the committee of the period of `slot + 1`. If the last slot of a sync
+    /// committee period is empty, any earlier slot of the period is signed by the next committee,
+    /// which the circuit can't select. `Backward` therefore walks forward from that slot, and the
+    /// update moves into the next period like with `Forward`. The circuit treats the first slot of
+    /// a period as a committee transition if the previous update was signed in the period before,
+    /// see `helpers::is_committee_transition`.
+    ///
+    /// # Arguments
+    /// * `target_slot` - Slot the epoch update should be generated for
+    ///
+    /// # Returns
+    /// * `Vec<u64>` - The candidate slots, starting with `target_slot`
+    pub fn candidate_slots(&self, target_slot: u64) -> Vec<u64> {
+        match self {
+            EmptySlotPolicy::Backward if !helpers::is_committee_transition_slot(target_slot) => {
+                let epoch_start =
+                    helpers::get_first_slot_for_epoch(helpers::slot_to_epoch_id(target_slot));
+                (epoch_start..=target_slot).rev().collect()
+            }
+            _ => (target_slot..target_slot + constants::MAX_SKIPPED_SLOTS_RETRY_ATTEMPTS).collect(),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct BankaiConfig {
     pub atlantic_endpoint: String,
     pub database_url: String,
+    pub empty_slot_policy: EmptySlotPolicy,
+    /// Directory large proofs are written to, they are kept in the database if unset
+    pub proof_blob_dir: Option<String>,
 }
 
 impl Default for BankaiConfig {
@@ -9,6 +55,8 @@ impl Default for BankaiConfig {
         Self {
             atlantic_endpoint: "https://staging.atlantic.api.herodotus.cloud".to_string(),
             database_url: "sqlite:./sqlite_state/bankai.db".to_string(),
+            empty_slot_policy: EmptySlotPolicy::default(),
+            proof_blob_dir: env::var("PROOF_BLOB_DIR").ok(),
         }
     }
 }
@@ -18,6 +66,8 @@ impl BankaiConfig {
         Self {
             atlantic_endpoint: "https://staging.atlantic.api.herodotus.cloud".to_string(),
             database_url: "sqlite:./sqlite_state/bankai.db".to_string(),
+            empty_slot_policy: EmptySlotPolicy::default(),
+            proof_blob_dir: env::var("PROOF_BLOB_DIR").ok(),
         }
     }
 }
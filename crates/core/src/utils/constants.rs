@@ -4,3 +4,9 @@ pub const TARGET_BATCH_SIZE: u64 = 32;
 pub const EPOCHS_PER_SYNC_COMMITTEE: u64 = 256;
 pub const MAX_SKIPPED_SLOTS_RETRY_ATTEMPTS: u64 = 5;
 pub const GENESIS_EPOCH: u64 = 242432;
+pub const SLOTS_PER_HISTORICAL_ROOT: u64 = 8192;
+
+// Fork slots of Sepolia, the network of the `cairo/src/utils/domain.cairo` fork schedule the
+// circuit uses
+pub const CAPELLA_FORK_SLOT: u64 = 1818624;
+pub const ELECTRA_FORK_SLOT: u64 = 7118848;
@@ -68,6 +68,20 @@ pub fn get_sync_committee_id_by_slot(slot: u64) -> u64 {
     slot / SLOTS_PER_SYNC_COMMITTEE
 }
 
+/// Returns true if the slot is the last slot of its sync committee period.
+/// A header at this slot is signed by the committee of the next period.
+pub fn is_committee_transition_slot(slot: u64) -> bool {
+    (slot + 1) % SLOTS_PER_SYNC_COMMITTEE == 0
+}
+
+/// Returns true if an update at `slot` is signed by a later committee than the previous update
+/// at `previous_slot`, like the recursive circuit decides it.
+/// The committee is the one of the period of `slot + 1`, so this also holds for the first slot
+/// of a period that is used because the last slot of the previous period was empty.
+pub fn is_committee_transition(previous_slot: u64, slot: u64) -> bool {
+    get_sync_committee_id_by_slot(slot + 1) != get_sync_committee_id_by_slot(previous_slot + 1)
+}
+
 pub fn get_first_slot_for_sync_committee(sync_committee: u64) -> u64 {
     sync_committee * SLOTS_PER_SYNC_COMMITTEE
 }
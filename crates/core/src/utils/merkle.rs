@@ -38,6 +38,108 @@ pub(crate) mod sha256 {
         value
     }
 
+    /// Hashes two sibling nodes into their parent
+    pub fn hash_pair(left: &FixedBytes<32>, right: &FixedBytes<32>) -> FixedBytes<32> {
+        let mut data = [0u8; 64];
+        data[0..32].copy_from_slice(left.as_slice());
+        data[32..64].copy_from_slice(right.as_slice());
+        FixedBytes::from_slice(&Sha256::digest(data))
+    }
+
+    /// Returns the roots of all-zero subtrees, indexed by their depth
+    pub fn zero_hashes(depth: usize) -> Vec<FixedBytes<32>> {
+        let mut hashes = vec![FixedBytes::ZERO];
+        for i in 0..depth {
+            hashes.push(hash_pair(&hashes[i], &hashes[i]));
+        }
+        hashes
+    }
+
+    /// Computes the SSZ merkle root of the leaves in a tree of the given depth.
+    /// Missing leaves are padded with zero subtrees, so the tree is never fully allocated.
+    pub fn merkleize(
+        leaves: &[FixedBytes<32>],
+        depth: usize,
+    ) -> Result<FixedBytes<32>, MerkleError> {
+        if leaves.len() as u128 > 1u128 << depth {
+            return Err(MerkleError::InvalidMerkleTree);
+        }
+
+        let zero_hashes = zero_hashes(depth);
+        let mut current_level = leaves.to_vec();
+        for zero_hash in zero_hashes.iter().take(depth) {
+            if current_level.len() % 2 == 1 {
+                current_level.push(*zero_hash);
+            }
+            current_level = current_level
+                .chunks(2)
+                .map(|pair| hash_pair(&pair[0], &pair[1]))
+                .collect();
+        }
+
+        Ok(current_level.first().copied().unwrap_or(zero_hashes[depth]))
+    }
+
+    /// Generates the SSZ merkle path of a leaf in a tree of the given depth.
+    /// The path is ordered from the leaf level up and can be verified with `hash_path`.
+    pub fn generate_path_with_depth(
+        leaves: &[FixedBytes<32>],
+        leaf_index: usize,
+        depth: usize,
+    ) -> Result<Vec<FixedBytes<32>>, MerkleError> {
+        if leaf_index >= leaves.len() || leaves.len() as u128 > 1u128 << depth {
+            return Err(MerkleError::InvalidMerkleTree);
+        }
+
+        let zero_hashes = zero_hashes(depth);
+        let mut path = Vec::with_capacity(depth);
+        let mut current_level = leaves.to_vec();
+        let mut current_index = leaf_index;
+        for zero_hash in zero_hashes.iter().take(depth) {
+            if current_level.len() % 2 == 1 {
+                current_level.push(*zero_hash);
+            }
+            path.push(current_level[current_index ^ 1]);
+
+            current_level = current_level
+                .chunks(2)
+                .map(|pair| hash_pair(&pair[0], &pair[1]))
+                .collect();
+            current_index /= 2;
+        }
+
+        Ok(path)
+    }
+
+    /// Mixes the length of an SSZ list into the root of its elements
+    pub fn mix_in_length(root: &FixedBytes<32>, length: usize) -> FixedBytes<32> {
+        hash_pair(root, &length_chunk(length))
+    }
+
+    /// Encodes a list length as SSZ chunk (little-endian, right padded)
+    pub fn length_chunk(length: usize) -> FixedBytes<32> {
+        uint64_chunk(length as u64)
+    }
+
+    /// Encodes a `uint64` as SSZ chunk (little-endian, right padded)
+    pub fn uint64_chunk(value: u64) -> FixedBytes<32> {
+        let mut chunk = [0u8; 32];
+        chunk[0..8].copy_from_slice(&value.to_le_bytes());
+        FixedBytes::from(chunk)
+    }
+
+    /// Packs raw bytes into right padded 32 byte SSZ chunks
+    pub fn pack_bytes(bytes: &[u8]) -> Vec<FixedBytes<32>> {
+        bytes
+            .chunks(32)
+            .map(|chunk| {
+                let mut padded = [0u8; 32];
+                padded[..chunk.len()].copy_from_slice(chunk);
+                FixedBytes::from(padded)
+            })
+            .collect()
+    }
+
     #[allow(dead_code)]
     pub fn generate_path(
         leaves: Vec<FixedBytes<32>>,
@@ -0,0 +1,92 @@
+//! Checks that blob commitments are only accepted against a beacon header with a proven root
+
+use alloy_primitives::FixedBytes;
+use bankai_core::fetcher::{
+    blob_commitment_proof::{BlobCommitmentError, BlobCommitmentProof},
+    recursive_epoch_input::{BeaconHeader, RecursiveEpochOutput},
+};
+use beacon_types::TreeHash;
+use sha2::{Digest, Sha256};
+
+/// Field index of `blob_kzg_commitments` in the `BeaconBlockBody` container
+const BLOB_KZG_COMMITMENTS_LEAF_INDEX: u64 = 11;
+
+fn hash_pair(left: &[u8], right: &[u8]) -> FixedBytes<32> {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    FixedBytes::from_slice(&hasher.finalize())
+}
+
+/// A commitment proof, the beacon header whose body includes it and the matching outputs
+fn proven_commitment() -> (BlobCommitmentProof, BeaconHeader, RecursiveEpochOutput) {
+    let commitment = FixedBytes::<48>::repeat_byte(0xc0);
+    let index = 3u64;
+    let path: Vec<FixedBytes<32>> = (0..17).map(|i| FixedBytes::repeat_byte(0x10 + i)).collect();
+
+    // A 48 byte commitment is right-padded to two chunks
+    let mut body_root = hash_pair(commitment.as_slice(), &[0u8; 16]);
+    let gindex = index | (BLOB_KZG_COMMITMENTS_LEAF_INDEX << 13);
+    for (depth, sibling) in path.iter().enumerate() {
+        body_root = if (gindex >> depth) & 1 == 0 {
+            hash_pair(body_root.as_slice(), sibling.as_slice())
+        } else {
+            hash_pair(sibling.as_slice(), body_root.as_slice())
+        };
+    }
+
+    let header = BeaconHeader {
+        slot: 11_000_000,
+        proposer_index: 42,
+        parent_root: FixedBytes::repeat_byte(0x21),
+        state_root: FixedBytes::repeat_byte(0x22),
+        body_root,
+    };
+    let outputs = RecursiveEpochOutput {
+        beacon_header_root: FixedBytes::from_slice(header.tree_hash_root().as_slice()),
+        beacon_state_root: header.state_root,
+        beacon_height: header.slot,
+        n_signers: 512,
+        execution_header_root: FixedBytes::repeat_byte(0x44),
+        execution_header_height: 21_000_000,
+        current_committee_hash: FixedBytes::repeat_byte(0x31),
+        next_committee_hash: FixedBytes::ZERO,
+    };
+    let proof = BlobCommitmentProof {
+        slot: header.slot,
+        index,
+        commitment,
+        path,
+    };
+
+    (proof, header, outputs)
+}
+
+#[test]
+fn accepts_commitment_of_proven_header() {
+    let (proof, header, outputs) = proven_commitment();
+    proof.verify(&header, &outputs).unwrap();
+}
+
+#[test]
+fn rejects_unproven_header() {
+    // A header with a forged body root, as a malicious beacon RPC could return
+    let (proof, mut header, outputs) = proven_commitment();
+    header.body_root = FixedBytes::repeat_byte(0x99);
+
+    assert!(matches!(
+        proof.verify(&header, &outputs),
+        Err(BlobCommitmentError::HeaderRootMismatch { .. })
+    ));
+}
+
+#[test]
+fn rejects_other_commitment() {
+    let (mut proof, header, outputs) = proven_commitment();
+    proof.commitment = FixedBytes::repeat_byte(0xc1);
+
+    assert!(matches!(
+        proof.verify(&header, &outputs),
+        Err(BlobCommitmentError::BodyRootMismatch { .. })
+    ));
+}
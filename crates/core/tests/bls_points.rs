@@ -0,0 +1,164 @@
+//! Checks the decoding of BLS points from their JSON encodings and their conversion to Cairo limbs
+
+use bankai_core::fetcher::recursive_epoch_input::{G1Point, G2Point};
+use bls12_381::{G1Affine, G1Projective, G2Affine, G2Projective, Scalar};
+use cairo_types::{G1PointCairo, G2PointCairo, UInt384};
+use serde_json::{json, Value};
+
+/// Compressed encoding of the G1 generator
+const G1_GENERATOR: &str = "0x97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+/// Compressed encoding of the G2 generator, `x1 || x0`
+const G2_GENERATOR: &str = "0x93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+/// x coordinate of the G2 generator, `x = x0 + x1 * u`
+const G2_GENERATOR_X0: &str = "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+const G2_GENERATOR_X1: &str = "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e";
+
+fn hex(bytes: impl AsRef<[u8]>) -> Value {
+    json!(format!("0x{}", hex::encode(bytes)))
+}
+
+/// Compressed encoding of a field element `x` with only the compression flag set
+fn compressed(x: u8, len: usize) -> Value {
+    let mut bytes = vec![0u8; len];
+    bytes[0] = 0x80;
+    bytes[len - 1] = x;
+    hex(bytes)
+}
+
+fn g1_points() -> Vec<G1Affine> {
+    vec![
+        G1Affine::generator(),
+        G1Affine::from(G1Projective::generator() * Scalar::from(0x1234_5678u64)),
+    ]
+}
+
+fn g2_points() -> Vec<G2Affine> {
+    vec![
+        G2Affine::generator(),
+        G2Affine::from(G2Projective::generator() * Scalar::from(0x1234_5678u64)),
+    ]
+}
+
+fn uint384(hex: &str) -> UInt384 {
+    UInt384::from_bytes_be(&hex::decode(hex).unwrap())
+}
+
+#[test]
+fn decodes_g1_encodings() {
+    let point: G1Point = serde_json::from_value(json!(G1_GENERATOR)).unwrap();
+    assert_eq!(point.0, G1Affine::generator());
+
+    for expected in g1_points() {
+        let encodings = [
+            hex(expected.to_compressed()),
+            hex(expected.to_uncompressed()),
+            serde_json::to_value(G1Point(expected)).unwrap(),
+        ];
+        for encoding in encodings {
+            let point: G1Point = serde_json::from_value(encoding.clone()).unwrap();
+            assert_eq!(point.0, expected, "{encoding}");
+        }
+    }
+}
+
+#[test]
+fn decodes_g2_encodings() {
+    let point: G2Point = serde_json::from_value(json!(G2_GENERATOR)).unwrap();
+    assert_eq!(point.0, G2Affine::generator());
+
+    for expected in g2_points() {
+        let encodings = [
+            hex(expected.to_compressed()),
+            hex(expected.to_uncompressed()),
+            serde_json::to_value(G2Point(expected)).unwrap(),
+        ];
+        for encoding in encodings {
+            let point: G2Point = serde_json::from_value(encoding.clone()).unwrap();
+            assert_eq!(point.0, expected, "{encoding}");
+        }
+    }
+
+    // Coordinates are given as `x0, x1`, the reverse of the byte encoding
+    let coordinates = serde_json::to_value(G2Point(G2Affine::generator())).unwrap();
+    assert_eq!(coordinates["x0"], json!(format!("0x{G2_GENERATOR_X0}")));
+    assert_eq!(coordinates["x1"], json!(format!("0x{G2_GENERATOR_X1}")));
+}
+
+#[test]
+fn rejects_off_curve_points() {
+    let one = hex([[0u8; 47].as_slice(), &[1]].concat());
+
+    let error = serde_json::from_value::<G1Point>(json!({ "x": one, "y": one })).unwrap_err();
+    assert!(error.to_string().contains("not on the curve"), "{error}");
+
+    let error = serde_json::from_value::<G2Point>(json!({
+        "x0": one,
+        "x1": one,
+        "y0": one,
+        "y1": one,
+    }))
+    .unwrap_err();
+    assert!(error.to_string().contains("not on the curve"), "{error}");
+
+    // x = 1 has no y on the G1 curve
+    assert!(serde_json::from_value::<G1Point>(compressed(1, 48)).is_err());
+}
+
+#[test]
+fn rejects_points_outside_subgroup() {
+    // x = 4 and x = 2 are on the curves, but the points have a cofactor component
+    let error = serde_json::from_value::<G1Point>(compressed(4, 48)).unwrap_err();
+    assert!(error.to_string().contains("subgroup"), "{error}");
+
+    let error = serde_json::from_value::<G2Point>(compressed(2, 96)).unwrap_err();
+    assert!(error.to_string().contains("subgroup"), "{error}");
+}
+
+#[test]
+fn rejects_malformed_encodings() {
+    let short = hex([0x80u8; 47]);
+    assert!(serde_json::from_value::<G1Point>(short.clone()).is_err());
+    assert!(serde_json::from_value::<G2Point>(short).is_err());
+    assert!(serde_json::from_value::<G1Point>(json!("0xzz")).is_err());
+    assert!(serde_json::from_value::<G1Point>(json!({ "x": "0x01" })).is_err());
+}
+
+#[test]
+fn g1_limbs_match_json_conversion() {
+    for point in g1_points() {
+        // The conversion used to round trip through the JSON coordinates
+        let json: G1PointCairo =
+            serde_json::from_value(serde_json::to_value(G1Point(point)).unwrap()).unwrap();
+        assert_eq!(G1PointCairo::from(G1Point(point)), json);
+    }
+}
+
+#[test]
+fn g2_limbs_match_json_conversion() {
+    for point in g2_points() {
+        let json: G2PointCairo =
+            serde_json::from_value(serde_json::to_value(G2Point(point)).unwrap()).unwrap();
+        assert_eq!(G2PointCairo::from(G2Point(point)), json);
+    }
+
+    let generator = G2PointCairo::from(G2Point(G2Affine::generator()));
+    assert_eq!(generator.x0, uint384(G2_GENERATOR_X0));
+    assert_eq!(generator.x1, uint384(G2_GENERATOR_X1));
+}
+
+#[test]
+fn limbs_exclude_flag_bits() {
+    // The uncompressed encoding of the identity sets the infinity flag
+    assert_eq!(G1Affine::identity().to_uncompressed()[0], 0x40);
+    assert_eq!(G2Affine::identity().to_uncompressed()[0], 0x40);
+
+    let zero = UInt384::from_bytes_be(&[0u8; 48]);
+    let g1 = G1PointCairo::from(G1Point(G1Affine::identity()));
+    assert_eq!((g1.x, g1.y), (zero.clone(), zero.clone()));
+
+    let g2 = G2PointCairo::from(G2Point(G2Affine::identity()));
+    assert_eq!(
+        [g2.x0, g2.x1, g2.y0, g2.y1],
+        [zero.clone(), zero.clone(), zero.clone(), zero]
+    );
+}
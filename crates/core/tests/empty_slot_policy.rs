@@ -0,0 +1,151 @@
+//! Checks the slots chosen by each empty slot policy around epoch and sync committee boundaries,
+//! and the committee transitions detected for them
+
+use bankai_core::utils::{
+    config::EmptySlotPolicy,
+    constants::{MAX_SKIPPED_SLOTS_RETRY_ATTEMPTS, SLOTS_PER_EPOCH},
+    helpers::{
+        get_last_slot_for_sync_committee, get_sync_committee_id_by_slot, is_committee_transition,
+    },
+};
+
+const POLICIES: [EmptySlotPolicy; 2] = [EmptySlotPolicy::Backward, EmptySlotPolicy::Forward];
+
+/// Last slot of sync committee period 1000
+const PERIOD_END: u64 = 1001 * 8192 - 1;
+/// Last slot of an epoch in the middle of period 1000
+const EPOCH_END: u64 = PERIOD_END - 100 * SLOTS_PER_EPOCH;
+
+/// Returns the slot a policy resolves to, given the empty slots
+fn resolve(policy: EmptySlotPolicy, target_slot: u64, empty: &[u64]) -> Option<u64> {
+    policy
+        .candidate_slots(target_slot)
+        .into_iter()
+        .find(|slot| !empty.contains(slot))
+}
+
+/// Returns the slot of the block signing `slot`, i.e. the next non-empty slot
+fn signature_slot(slot: u64, empty: &[u64]) -> u64 {
+    (slot + 1..).find(|slot| !empty.contains(slot)).unwrap()
+}
+
+#[test]
+fn backward_is_default() {
+    assert_eq!(EmptySlotPolicy::default(), EmptySlotPolicy::Backward);
+}
+
+#[test]
+fn non_empty_target_is_used() {
+    for policy in POLICIES {
+        assert_eq!(
+            resolve(policy, EPOCH_END, &[]),
+            Some(EPOCH_END),
+            "{policy:?}"
+        );
+        assert_eq!(
+            resolve(policy, PERIOD_END, &[]),
+            Some(PERIOD_END),
+            "{policy:?}"
+        );
+    }
+}
+
+#[test]
+fn empty_first_slot() {
+    // The first slot of the target epoch and of the following epoch are empty
+    let epoch_start = EPOCH_END + 1 - SLOTS_PER_EPOCH;
+    let empty = [epoch_start, EPOCH_END, EPOCH_END + 1];
+
+    assert_eq!(
+        resolve(EmptySlotPolicy::Backward, EPOCH_END, &empty),
+        Some(EPOCH_END - 1)
+    );
+    assert_eq!(
+        resolve(EmptySlotPolicy::Forward, EPOCH_END, &empty),
+        Some(EPOCH_END + 2)
+    );
+
+    // Backward never leaves the target epoch
+    let empty: Vec<u64> = (epoch_start + 1..=EPOCH_END).collect();
+    assert_eq!(
+        resolve(EmptySlotPolicy::Backward, EPOCH_END, &empty),
+        Some(epoch_start)
+    );
+}
+
+#[test]
+fn empty_last_slot_of_period() {
+    let empty = [PERIOD_END];
+    let previous_slot = PERIOD_END - SLOTS_PER_EPOCH;
+
+    for policy in POLICIES {
+        let slot = resolve(policy, PERIOD_END, &empty).unwrap();
+        assert_eq!(slot, PERIOD_END + 1, "{policy:?}");
+
+        // The update is signed by the next committee, which is detected as a transition from
+        // the period the previous update was signed in
+        assert_eq!(
+            get_sync_committee_id_by_slot(signature_slot(slot, &empty)),
+            get_sync_committee_id_by_slot(previous_slot) + 1,
+            "{policy:?}"
+        );
+        assert!(is_committee_transition(previous_slot, slot), "{policy:?}");
+
+        // The following update is signed by the same committee
+        assert!(!is_committee_transition(slot, slot + SLOTS_PER_EPOCH));
+    }
+    assert_eq!(
+        get_last_slot_for_sync_committee(get_sync_committee_id_by_slot(PERIOD_END)),
+        PERIOD_END
+    );
+}
+
+#[test]
+fn transition_follows_previous_update() {
+    // The last slot of a period is signed in the next period
+    assert!(is_committee_transition(
+        PERIOD_END - SLOTS_PER_EPOCH,
+        PERIOD_END
+    ));
+    assert!(!is_committee_transition(
+        PERIOD_END,
+        PERIOD_END + SLOTS_PER_EPOCH
+    ));
+
+    // Within a period, from an empty epoch end of the previous update as well
+    assert!(!is_committee_transition(
+        EPOCH_END - SLOTS_PER_EPOCH,
+        EPOCH_END
+    ));
+    assert!(!is_committee_transition(EPOCH_END - 3, EPOCH_END + 2));
+}
+
+#[test]
+fn empty_run_longer_than_bound() {
+    // Backward is bounded by the start of the target epoch
+    let empty: Vec<u64> = (EPOCH_END + 1 - SLOTS_PER_EPOCH..=EPOCH_END).collect();
+    assert_eq!(resolve(EmptySlotPolicy::Backward, EPOCH_END, &empty), None);
+    assert_eq!(
+        EmptySlotPolicy::Backward.candidate_slots(EPOCH_END).len() as u64,
+        SLOTS_PER_EPOCH
+    );
+
+    // Forward and the period boundary are bounded by the retry limit
+    for target_slot in [EPOCH_END, PERIOD_END] {
+        let empty: Vec<u64> =
+            (target_slot..target_slot + MAX_SKIPPED_SLOTS_RETRY_ATTEMPTS).collect();
+        for policy in POLICIES {
+            if policy == EmptySlotPolicy::Backward && target_slot == EPOCH_END {
+                continue;
+            }
+            assert_eq!(resolve(policy, target_slot, &empty), None, "{policy:?}");
+        }
+
+        // The last slot within the bound is still found
+        let empty = &empty[..empty.len() - 1];
+        assert_eq!(
+            resolve(EmptySlotPolicy::Forward, target_slot, empty),
+            Some(target_slot + MAX_SKIPPED_SLOTS_RETRY_ATTEMPTS - 1)
+        );
+    }
+}
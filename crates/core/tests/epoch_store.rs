@@ -0,0 +1,490 @@
+//! Conformance suite shared by all `EpochStore` backends
+//!
+//! The Postgres store is only checked if `POSTGRES_TEST_URL` points to a throwaway database.
+
+use alloy_primitives::FixedBytes;
+use bankai_core::{
+    db::{BlobStore, EpochStore, MemoryStore, PostgresStore, SqliteStore, Status, StoreError},
+    fetcher::recursive_epoch_input::RecursiveEpochOutput,
+};
+use sqlx::{
+    sqlite::{SqliteConnectOptions, SqlitePool},
+    Executor,
+};
+use std::str::FromStr;
+use uuid::Uuid;
+
+fn outputs(beacon_height: u64, execution_header_height: u64) -> RecursiveEpochOutput {
+    RecursiveEpochOutput {
+        beacon_header_root: FixedBytes::left_padding_from(&beacon_height.to_be_bytes()),
+        beacon_state_root: FixedBytes::repeat_byte(2),
+        beacon_height,
+        n_signers: 400,
+        execution_header_root: FixedBytes::left_padding_from(
+            &execution_header_height.to_be_bytes(),
+        ),
+        execution_header_height,
+        current_committee_hash: FixedBytes::repeat_byte(4),
+        next_committee_hash: FixedBytes::repeat_byte(5),
+    }
+}
+
+async fn check_store(store: &dyn EpochStore) {
+    assert!(store.get_latest_epoch_update().await.unwrap().is_none());
+    assert!(store
+        .get_latest_proven_epoch_update()
+        .await
+        .unwrap()
+        .is_none());
+    assert!(store.get_all_epoch_updates().await.unwrap().is_empty());
+
+    let first = store
+        .create_epoch_update(10, 351, outputs(351, 1000), 1_700_000_000)
+        .await
+        .unwrap();
+    let second = store
+        .create_epoch_update(11, 383, outputs(383, 1030), 1_700_000_360)
+        .await
+        .unwrap();
+
+    let update = store
+        .get_epoch_update_by_uuid(&first)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(update.epoch_number, 10);
+    assert_eq!(update.slot_number, 351);
+    assert_eq!(update.status, "fetching");
+    assert_eq!(update.outputs.unwrap().beacon_height, 351);
+    assert_eq!(update.execution_timestamp, Some(1_700_000_000));
+    assert!(store
+        .get_epoch_update_by_uuid("unknown")
+        .await
+        .unwrap()
+        .is_none());
+
+    // The latest update is the one with the highest slot
+    let latest = store.get_latest_epoch_update().await.unwrap().unwrap();
+    assert_eq!(latest.uuid, second);
+
+    let all = store.get_all_epoch_updates().await.unwrap();
+    let slots: Vec<_> = all.iter().map(|update| update.slot_number).collect();
+    assert_eq!(slots, vec![383, 351]);
+
+    store
+        .update_outputs(&first, &outputs(351, 1001))
+        .await
+        .unwrap();
+    store.add_atlantic_id(&first, "atlantic-1").await.unwrap();
+    store.update_status(&first, Status::TraceGen).await.unwrap();
+    store.update_status(&first, Status::Proving).await.unwrap();
+    let update = store
+        .get_epoch_update_by_uuid(&first)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(update.outputs.unwrap().execution_header_height, 1001);
+    assert_eq!(update.atlantic_id.as_deref(), Some("atlantic-1"));
+    assert_eq!(update.status, "proving");
+
+    // Proofs are only found through the epoch update they are attached to
+    let proof_id = store.add_proof(r#"{"proof":1}"#).await.unwrap();
+    assert_eq!(
+        store.get_proof(proof_id).await.unwrap().unwrap().proof,
+        r#"{"proof":1}"#
+    );
+    assert!(store
+        .get_proof_by_beacon_height(351)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Identical proofs are stored once
+    assert_eq!(store.add_proof(r#"{"proof":1}"#).await.unwrap(), proof_id);
+
+    store.update_proof_id(&first, proof_id).await.unwrap();
+    store.update_status(&first, Status::Done).await.unwrap();
+
+    let by_beacon = store
+        .get_proof_by_beacon_height(351)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(by_beacon.id, proof_id);
+    let by_execution = store
+        .get_proof_by_execution_height(1001)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(by_execution.id, proof_id);
+    assert!(store
+        .get_proof_by_execution_height(1030)
+        .await
+        .unwrap()
+        .is_none());
+
+    let proven = store
+        .get_latest_proven_epoch_update()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(proven.uuid, first);
+    assert_eq!(proven.proof_id, Some(proof_id));
+
+    // Failed updates are skipped by the latest update
+    store
+        .update_error(&second, "trace gen failed")
+        .await
+        .unwrap();
+    let failed = store
+        .get_epoch_update_by_uuid(&second)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(failed.status, "error");
+    assert_eq!(failed.error_reason.as_deref(), Some("trace gen failed"));
+    let latest = store.get_latest_epoch_update().await.unwrap().unwrap();
+    assert_eq!(latest.uuid, first);
+
+    check_transitions(store, &first, &second).await;
+    check_proven_queries(store, &first, proof_id).await;
+}
+
+/// Expects `first` to be done and `second` to have failed while fetching
+async fn check_transitions(store: &dyn EpochStore, first: &str, second: &str) {
+    let events = store.get_epoch_update_events(first).await.unwrap();
+    let transitions: Vec<_> = events
+        .iter()
+        .map(|event| (event.from_status.as_deref(), event.to_status.as_str()))
+        .collect();
+    assert_eq!(
+        transitions,
+        vec![
+            (None, "fetching"),
+            (Some("fetching"), "trace_gen"),
+            (Some("trace_gen"), "proving"),
+            (Some("proving"), "done"),
+        ]
+    );
+    assert!(events
+        .windows(2)
+        .all(|pair| pair[0].created_at <= pair[1].created_at));
+
+    let failed = store.get_epoch_update_events(second).await.unwrap();
+    let last = failed.last().unwrap();
+    assert_eq!(last.from_status.as_deref(), Some("fetching"));
+    assert_eq!(last.to_status, "error");
+    assert_eq!(last.error_reason.as_deref(), Some("trace gen failed"));
+
+    // Skipping or reverting a stage is rejected and leaves the status unchanged
+    let pending = store
+        .create_epoch_update(20, 671, outputs(671, 1300), 1_700_003_840)
+        .await
+        .unwrap();
+    assert!(matches!(
+        store.update_status(&pending, Status::Proving).await,
+        Err(StoreError::InvalidTransition {
+            from: Status::Fetching,
+            to: Status::Proving,
+            ..
+        })
+    ));
+    assert!(matches!(
+        store.update_status(first, Status::Fetching).await,
+        Err(StoreError::InvalidTransition { .. })
+    ));
+    assert!(matches!(
+        store.update_status("unknown", Status::TraceGen).await,
+        Err(StoreError::EpochUpdateNotFound(_))
+    ));
+    let update = store
+        .get_epoch_update_by_uuid(&pending)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(update.status, "fetching");
+    assert_eq!(
+        store.get_epoch_update_events(&pending).await.unwrap().len(),
+        1
+    );
+
+    // Any state can fail
+    store.update_error(&pending, "fetch failed").await.unwrap();
+    store.update_error(&pending, "still failing").await.unwrap();
+    let update = store
+        .get_epoch_update_by_uuid(&pending)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(update.error_reason.as_deref(), Some("still failing"));
+}
+
+/// Expects `first` to be proven with execution height 1001 and the update at 1030 to have failed
+async fn check_proven_queries(store: &dyn EpochStore, first: &str, first_proof: i64) {
+    let third = store
+        .create_epoch_update(12, 415, outputs(415, 1060), 1_700_000_720)
+        .await
+        .unwrap();
+    let third_proof = store.add_proof(r#"{"proof":3}"#).await.unwrap();
+    store.update_proof_id(&third, third_proof).await.unwrap();
+    for status in [Status::TraceGen, Status::Proving, Status::Done] {
+        store.update_status(&third, status).await.unwrap();
+    }
+
+    // Unproven updates are never returned
+    let pending = store
+        .create_epoch_update(13, 447, outputs(447, 1090), 1_700_000_999)
+        .await
+        .unwrap();
+    store
+        .add_execution_timestamp(&pending, 1_700_001_080)
+        .await
+        .unwrap();
+
+    assert!(store
+        .get_proven_epoch_updates_without_execution_timestamp()
+        .await
+        .unwrap()
+        .is_empty());
+
+    let nearest = |block: u64| async move {
+        store
+            .get_nearest_proven_epoch_by_execution_height(block)
+            .await
+            .unwrap()
+            .map(|proven| proven.uuid)
+    };
+    assert_eq!(nearest(900).await.as_deref(), Some(first));
+    assert_eq!(nearest(1001).await.as_deref(), Some(first));
+    assert_eq!(nearest(1002).await, Some(third.clone()));
+    assert_eq!(nearest(1061).await, None);
+
+    let proven = store
+        .get_nearest_proven_epoch_by_timestamp(1_700_000_001)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(proven.uuid, third);
+    assert_eq!(proven.proof_id, third_proof);
+    assert_eq!(proven.outputs.execution_header_height, 1060);
+    assert!(store
+        .get_nearest_proven_epoch_by_timestamp(1_700_000_721)
+        .await
+        .unwrap()
+        .is_none());
+
+    let in_range: Vec<_> = store
+        .get_proven_epochs_in_execution_range(1000, 1100)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|proven| (proven.uuid, proven.proof_id))
+        .collect();
+    assert_eq!(
+        in_range,
+        vec![
+            (first.to_string(), first_proof),
+            (third.clone(), third_proof)
+        ]
+    );
+    assert!(store
+        .get_proven_epochs_in_execution_range(1002, 1059)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let by_root = store
+        .get_proven_epoch_by_beacon_header_root(&outputs(415, 1060).beacon_header_root)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(by_root.uuid, third);
+    let by_hash = store
+        .get_proven_epoch_by_execution_block_hash(&outputs(351, 1001).execution_header_root)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(by_hash.uuid, first);
+    assert_eq!(by_hash.proof_id, first_proof);
+    assert!(store
+        .get_proven_epoch_by_execution_block_hash(&outputs(447, 1090).execution_header_root)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn memory_store() {
+    check_store(&MemoryStore::new()).await;
+}
+
+#[tokio::test]
+async fn sqlite_store() {
+    let path = std::env::temp_dir().join(format!("bankai-{}.db", Uuid::new_v4()));
+    let store = SqliteStore::new(&format!("sqlite:{}", path.display()))
+        .await
+        .unwrap();
+    check_store(&store).await;
+    std::fs::remove_file(path).ok();
+}
+
+#[tokio::test]
+async fn postgres_store() {
+    let Ok(url) = std::env::var("POSTGRES_TEST_URL") else {
+        eprintln!("POSTGRES_TEST_URL not set, skipping the Postgres store");
+        return;
+    };
+    let store = PostgresStore::new(&url).await.unwrap();
+    check_store(&store).await;
+}
+
+#[tokio::test]
+async fn sqlite_backfills_output_columns() {
+    let path = std::env::temp_dir().join(format!("bankai-{}.db", Uuid::new_v4()));
+    let url = format!("sqlite:{}", path.display());
+
+    // A database with only the initial schema and an update that predates the output columns
+    {
+        let options = SqliteConnectOptions::from_str(&url)
+            .unwrap()
+            .create_if_missing(true);
+        let pool = SqlitePool::connect_with(options).await.unwrap();
+        pool.execute(include_str!(
+            "../../../migrations/sqlite/20250301000000_init.sql"
+        ))
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO proofs (id, proof) VALUES (1, 'proof')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO epoch_updates (uuid, epoch_number, slot_number, outputs, proof_id, status)
+             VALUES ('legacy', 10, 351, ?, 1, 'done')",
+        )
+        .bind(serde_json::to_string(&outputs(351, 1000)).unwrap())
+        .execute(&pool)
+        .await
+        .unwrap();
+        pool.close().await;
+    }
+
+    let store = SqliteStore::new(&url).await.unwrap();
+    let proof = store
+        .get_proof_by_beacon_height(351)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(proof.id, 1);
+    let proof = store
+        .get_proof_by_execution_height(1000)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(proof.id, 1);
+    // Storing the legacy proof again resolves to its row
+    assert_eq!(store.add_proof("proof").await.unwrap(), 1);
+
+    // The execution timestamp isn't known to the database, it is filled in at runtime
+    let missing = store
+        .get_proven_epoch_updates_without_execution_timestamp()
+        .await
+        .unwrap();
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0].uuid, "legacy");
+    assert_eq!(missing[0].execution_timestamp, None);
+    store
+        .add_execution_timestamp("legacy", 1_700_000_000)
+        .await
+        .unwrap();
+    assert!(store
+        .get_proven_epoch_updates_without_execution_timestamp()
+        .await
+        .unwrap()
+        .is_empty());
+    std::fs::remove_file(path).ok();
+}
+
+#[tokio::test]
+async fn sqlite_rejects_unknown_migrations() {
+    let path = std::env::temp_dir().join(format!("bankai-{}.db", Uuid::new_v4()));
+    let url = format!("sqlite:{}", path.display());
+
+    // A version applied from outside the repo
+    SqliteStore::new(&url).await.unwrap();
+    {
+        let pool = SqlitePool::connect(&url).await.unwrap();
+        sqlx::query(
+            "INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time)
+             VALUES (20240101000000, 'unknown', TRUE, X'00', 0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        pool.close().await;
+    }
+
+    assert!(matches!(
+        SqliteStore::new(&url).await,
+        Err(StoreError::Migration(_))
+    ));
+    std::fs::remove_file(path).ok();
+}
+
+#[tokio::test]
+async fn sqlite_blob_store() {
+    let id = Uuid::new_v4();
+    let path = std::env::temp_dir().join(format!("bankai-{id}.db"));
+    let blob_dir = std::env::temp_dir().join(format!("bankai-blobs-{id}"));
+    let url = format!("sqlite:{}", path.display());
+
+    // Only proofs above the threshold are written to the blob store
+    let store = SqliteStore::new(&url)
+        .await
+        .unwrap()
+        .with_blob_store(BlobStore::new(&blob_dir, 64));
+    let small_proof = r#"{"proof":1}"#;
+    let large_proof = serde_json::json!({ "proof": (0..4096).collect::<Vec<u32>>() }).to_string();
+    let small_id = store.add_proof(small_proof).await.unwrap();
+    let large_id = store.add_proof(&large_proof).await.unwrap();
+    assert_eq!(std::fs::read_dir(&blob_dir).unwrap().count(), 1);
+
+    assert_eq!(
+        store.get_proof(small_id).await.unwrap().unwrap().proof,
+        small_proof
+    );
+    check_store(&store).await;
+    assert_eq!(
+        store.get_proof(large_id).await.unwrap().unwrap().proof,
+        large_proof
+    );
+    assert_eq!(store.add_proof(&large_proof).await.unwrap(), large_id);
+
+    // The database only references the blob, it can't be read without the blob store
+    let store = SqliteStore::new(&url).await.unwrap();
+    assert_eq!(
+        store.get_proof(small_id).await.unwrap().unwrap().proof,
+        small_proof
+    );
+    assert!(matches!(
+        store.get_proof(large_id).await,
+        Err(StoreError::MissingBlobStore(_))
+    ));
+
+    // A blob swapped for another proof is rejected
+    let blob = std::fs::read_dir(&blob_dir)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    std::fs::write(&blob, zstd::encode_all(small_proof.as_bytes(), 0).unwrap()).unwrap();
+    let store = store.with_blob_store(BlobStore::new(&blob_dir, 64));
+    assert!(matches!(
+        store.get_proof(large_id).await,
+        Err(StoreError::ProofHashMismatch { id, .. }) if id == large_id
+    ));
+
+    std::fs::remove_file(path).ok();
+    std::fs::remove_dir_all(blob_dir).ok();
+}
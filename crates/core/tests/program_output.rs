@@ -0,0 +1,242 @@
+//! Checks the encoding of the recursive epoch program output, its decoding from a PIE and its
+//! comparison with the natively computed output
+
+use alloy_primitives::FixedBytes;
+use bankai_core::{
+    conversion::{OutputError, OutputMismatch},
+    fetcher::recursive_epoch_input::RecursiveEpochOutput,
+};
+use cairo_runner::error::Error as RunnerError;
+use cairo_vm::{
+    types::{builtin_name::BuiltinName, relocatable::MaybeRelocatable},
+    vm::runners::{
+        cairo_pie::{
+            CairoPie, CairoPieAdditionalData, CairoPieMemory, CairoPieMetadata, CairoPieVersion,
+            SegmentInfo, StrippedProgram,
+        },
+        cairo_runner::ExecutionResources,
+    },
+    Felt252,
+};
+use starknet_crypto::Felt;
+
+/// Index of the output builtin segment in the test PIEs
+const OUTPUT_SEGMENT: usize = 2;
+
+fn output() -> RecursiveEpochOutput {
+    RecursiveEpochOutput {
+        beacon_header_root: FixedBytes::from_slice(&(1..=32).collect::<Vec<u8>>()),
+        beacon_state_root: FixedBytes::repeat_byte(0x22),
+        beacon_height: 7_000_031,
+        n_signers: 480,
+        execution_header_root: FixedBytes::repeat_byte(0x44),
+        execution_header_height: 6_500_000,
+        current_committee_hash: FixedBytes::repeat_byte(0x55),
+        next_committee_hash: FixedBytes::repeat_byte(0x66),
+    }
+}
+
+/// Builds a PIE whose output builtin segment holds `cells`, as `(offset, value)` pairs
+fn pie(cells: Vec<(usize, Felt)>, size: usize) -> CairoPie {
+    let segment = |index, size| SegmentInfo { index, size };
+
+    CairoPie {
+        metadata: CairoPieMetadata {
+            program: StrippedProgram {
+                data: vec![],
+                builtins: vec![BuiltinName::output],
+                main: 0,
+                prime: (),
+            },
+            program_segment: segment(0, 0),
+            execution_segment: segment(1, 0),
+            ret_fp_segment: segment(3, 0),
+            ret_pc_segment: segment(4, 0),
+            builtin_segments: [(BuiltinName::output, segment(OUTPUT_SEGMENT as isize, size))]
+                .into_iter()
+                .collect(),
+            extra_segments: vec![],
+        },
+        memory: CairoPieMemory(
+            cells
+                .into_iter()
+                .map(|(offset, value)| {
+                    let value = Felt252::from_bytes_be(&value.to_bytes_be());
+                    ((OUTPUT_SEGMENT, offset), MaybeRelocatable::Int(value))
+                })
+                .collect(),
+        ),
+        execution_resources: ExecutionResources::default(),
+        additional_data: CairoPieAdditionalData(Default::default()),
+        version: CairoPieVersion { cairo_pie: () },
+    }
+}
+
+fn output_pie(felts: Vec<Felt>) -> CairoPie {
+    let size = felts.len();
+    pie(felts.into_iter().enumerate().collect(), size)
+}
+
+#[test]
+fn decodes_pie_output() {
+    let expected = output();
+    let pie = output_pie(expected.to_felts());
+
+    assert_eq!(RecursiveEpochOutput::from_pie(&pie).unwrap(), expected);
+    assert_eq!(
+        RecursiveEpochOutput::check_pie(&pie, &expected).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn reports_mismatching_field_by_name() {
+    let expected = output();
+    let mut felts = expected.to_felts();
+    // The execution header height
+    felts[8] = Felt::from(6_500_001u64);
+
+    let error = RecursiveEpochOutput::check_pie(&output_pie(felts), &expected).unwrap_err();
+    let OutputError::Mismatch(mismatches) = &error else {
+        panic!("unexpected error: {error}");
+    };
+    assert_eq!(
+        mismatches,
+        &vec![OutputMismatch {
+            field: "execution_header_height",
+            expected: "6500000".to_string(),
+            actual: "6500001".to_string(),
+        }]
+    );
+    assert!(error
+        .to_string()
+        .contains("execution_header_height: expected 6500000, got 6500001"));
+}
+
+#[test]
+fn diff_of_equal_outputs_is_empty() {
+    assert!(output().diff(&output()).is_empty());
+
+    let mut other = output();
+    other.beacon_state_root = FixedBytes::repeat_byte(0x23);
+    other.n_signers = 479;
+    let fields: Vec<_> = output()
+        .diff(&other)
+        .into_iter()
+        .map(|mismatch| mismatch.field)
+        .collect();
+    assert_eq!(fields, vec!["beacon_state_root", "n_signers"]);
+}
+
+#[test]
+fn rejects_missing_output_segment() {
+    let mut pie = output_pie(output().to_felts());
+    pie.metadata.builtin_segments.clear();
+
+    assert!(matches!(
+        RecursiveEpochOutput::from_pie(&pie),
+        Err(OutputError::Runner(RunnerError::MissingOutputSegment))
+    ));
+}
+
+#[test]
+fn rejects_incomplete_output_segment() {
+    // A gap in the output cells
+    let mut cells: Vec<_> = output().to_felts().into_iter().enumerate().collect();
+    cells.remove(4);
+    assert!(matches!(
+        RecursiveEpochOutput::from_pie(&pie(cells, RecursiveEpochOutput::N_FELTS)),
+        Err(OutputError::Runner(RunnerError::InvalidOutput(_)))
+    ));
+
+    // A segment that is shorter than the program output
+    let felts = output().to_felts()[..12].to_vec();
+    assert!(matches!(
+        RecursiveEpochOutput::from_pie(&output_pie(felts)),
+        Err(OutputError::InvalidLength {
+            expected: 13,
+            actual: 12
+        })
+    ));
+}
+
+#[test]
+fn felts_round_trip() {
+    let felts = output().to_felts();
+    assert_eq!(felts.len(), RecursiveEpochOutput::N_FELTS);
+    // `low, high` limbs of the beacon header root
+    assert_eq!(
+        felts[0],
+        Felt::from_hex_unchecked("0x1112131415161718191a1b1c1d1e1f20")
+    );
+    assert_eq!(
+        felts[1],
+        Felt::from_hex_unchecked("0x0102030405060708090a0b0c0d0e0f10")
+    );
+
+    assert_eq!(RecursiveEpochOutput::from_felts(&felts).unwrap(), output());
+}
+
+#[test]
+fn rejects_next_committee_hash_at_transition_slot() {
+    let mut expected = output();
+    expected.beacon_height = 855 * 8192 - 1;
+
+    let error = RecursiveEpochOutput::from_felts(&expected.to_felts()).unwrap_err();
+    assert!(matches!(
+        error,
+        OutputError::InvalidCommitteeTransition(slot) if slot == expected.beacon_height
+    ));
+
+    // The next committee is unknown at the transition, so its hash is zero
+    expected.next_committee_hash = FixedBytes::ZERO;
+    assert_eq!(
+        RecursiveEpochOutput::from_felts(&expected.to_felts()).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn rejects_overflowing_felts() {
+    let limb_overflow = Felt::from_hex_unchecked("0x100000000000000000000000000000000");
+    for index in [0, 1, 12] {
+        let mut felts = output().to_felts();
+        felts[index] = limb_overflow;
+        assert!(matches!(
+            RecursiveEpochOutput::from_felts(&felts),
+            Err(OutputError::LimbOverflow)
+        ));
+    }
+
+    let value_overflow = Felt::from(u64::MAX) + Felt::ONE;
+    for index in [4, 5, 8] {
+        let mut felts = output().to_felts();
+        felts[index] = value_overflow;
+        assert!(matches!(
+            RecursiveEpochOutput::from_felts(&felts),
+            Err(OutputError::ValueOverflow(felt)) if felt == value_overflow
+        ));
+    }
+}
+
+#[test]
+fn expected_output_hash_matches_bootloader_output() {
+    let program_hash = Felt::from_hex_unchecked(
+        "0x5ab580b04e3532b6b18f81cfa654a05e29dd8e2352d88df1e765a84072db07",
+    );
+
+    // Poseidon of `[1, 15, program_hash, ...output]`, computed independently
+    assert_eq!(
+        output().expected_output_hash(program_hash),
+        Felt::from_hex_unchecked(
+            "0x3a7a48e52dc42e09716baff1c15ff21d4b80be19d755d5f1817b7f9143b61ab"
+        )
+    );
+
+    let mut felts = vec![Felt::ONE, Felt::from(15u64), program_hash];
+    felts.extend(output().to_felts());
+    assert_eq!(
+        output().expected_output_hash(program_hash),
+        starknet_crypto::poseidon_hash_many(&felts)
+    );
+}
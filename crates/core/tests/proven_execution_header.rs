@@ -0,0 +1,227 @@
+//! Checks that execution data is only accepted once it is bound to a proven beacon header
+
+use alloy_primitives::{Address, FixedBytes, B256};
+use alloy_trie::{EMPTY_ROOT_HASH, KECCAK_EMPTY};
+use bankai_core::{
+    execution::{account::AccountProof, ExecutionError, ProvenExecutionHeader},
+    fetcher::{
+        execution_header_input::ExecutionHeaderProof,
+        recursive_epoch_input::{BeaconHeader, RecursiveEpochOutput},
+    },
+};
+use beacon_types::{
+    ExecutionBlockHash, ExecutionPayloadHeader, ExecutionPayloadHeaderDeneb, Hash256,
+    MainnetEthSpec, TreeHash,
+};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+/// Field index of the execution payload in the beacon block body
+const PAYLOAD_INDEX: u64 = 9;
+
+fn hash_pair(left: FixedBytes<32>, right: FixedBytes<32>) -> FixedBytes<32> {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    FixedBytes::from_slice(&hasher.finalize())
+}
+
+fn payload_header(state_root: B256) -> ExecutionPayloadHeader<MainnetEthSpec> {
+    ExecutionPayloadHeader::Deneb(ExecutionPayloadHeaderDeneb {
+        state_root: Hash256::from_slice(state_root.as_slice()),
+        receipts_root: Hash256::repeat_byte(0x04),
+        block_number: 21_000_000,
+        timestamp: 1_740_000_000,
+        block_hash: ExecutionBlockHash::from_root(Hash256::repeat_byte(0x06)),
+        transactions_root: Hash256::repeat_byte(0x07),
+        withdrawals_root: Hash256::repeat_byte(0x08),
+        ..Default::default()
+    })
+}
+
+/// A beacon header whose body includes `payload`, the payload proof and the matching outputs
+fn proven_block(
+    payload: ExecutionPayloadHeader<MainnetEthSpec>,
+) -> (BeaconHeader, ExecutionHeaderProof, RecursiveEpochOutput) {
+    let leaf = FixedBytes::from_slice(payload.tree_hash_root().as_slice());
+    let path: Vec<FixedBytes<32>> = (0..4).map(|i| FixedBytes::repeat_byte(0x10 + i)).collect();
+
+    let mut body_root = leaf;
+    for (depth, sibling) in path.iter().enumerate() {
+        body_root = if (PAYLOAD_INDEX >> depth) & 1 == 0 {
+            hash_pair(body_root, *sibling)
+        } else {
+            hash_pair(*sibling, body_root)
+        };
+    }
+
+    let beacon_header = BeaconHeader {
+        slot: 11_000_000,
+        proposer_index: 42,
+        parent_root: FixedBytes::repeat_byte(0x21),
+        state_root: FixedBytes::repeat_byte(0x22),
+        body_root,
+    };
+    let outputs = RecursiveEpochOutput {
+        beacon_header_root: FixedBytes::from_slice(beacon_header.tree_hash_root().as_slice()),
+        beacon_state_root: beacon_header.state_root,
+        beacon_height: beacon_header.slot,
+        n_signers: 512,
+        execution_header_root: FixedBytes::from_slice(payload.block_hash().0.as_slice()),
+        execution_header_height: payload.block_number(),
+        current_committee_hash: FixedBytes::repeat_byte(0x31),
+        next_committee_hash: FixedBytes::ZERO,
+    };
+    let proof = ExecutionHeaderProof {
+        root: body_root,
+        path,
+        leaf,
+        index: PAYLOAD_INDEX as usize,
+        execution_payload_header: payload,
+        slot: beacon_header.slot,
+    };
+
+    (beacon_header, proof, outputs)
+}
+
+fn proven_header() -> ProvenExecutionHeader {
+    let (beacon_header, proof, outputs) = proven_block(payload_header(EMPTY_ROOT_HASH));
+    ProvenExecutionHeader::new(&beacon_header, proof, &outputs).unwrap()
+}
+
+/// An exclusion proof of an empty account in an empty state trie
+fn empty_account_proof(requested: Address, returned: Address, keys: Vec<B256>) -> AccountProof {
+    let storage_proof: Vec<_> = keys
+        .iter()
+        .map(|key| json!({ "key": key, "value": "0x0", "proof": [] }))
+        .collect();
+    let proof = serde_json::from_value(json!({
+        "address": returned,
+        "balance": "0x0",
+        "codeHash": KECCAK_EMPTY,
+        "nonce": "0x0",
+        "storageHash": EMPTY_ROOT_HASH,
+        "accountProof": [],
+        "storageProof": storage_proof,
+    }))
+    .unwrap();
+
+    AccountProof {
+        block_number: 21_000_000,
+        address: requested,
+        keys,
+        proof,
+    }
+}
+
+#[test]
+fn accepts_included_payload() {
+    let header = proven_header();
+    assert_eq!(header.block_number(), 21_000_000);
+    assert_eq!(header.state_root(), EMPTY_ROOT_HASH);
+}
+
+#[test]
+fn rejects_unproven_beacon_header() {
+    let (mut beacon_header, proof, outputs) = proven_block(payload_header(EMPTY_ROOT_HASH));
+    beacon_header.proposer_index += 1;
+
+    let result = ProvenExecutionHeader::new(&beacon_header, proof, &outputs);
+    assert!(matches!(
+        result,
+        Err(ExecutionError::RootMismatch {
+            field: "beacon_header_root",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn rejects_proof_of_other_slot() {
+    let (beacon_header, mut proof, outputs) = proven_block(payload_header(EMPTY_ROOT_HASH));
+    proof.slot += 1;
+
+    let result = ProvenExecutionHeader::new(&beacon_header, proof, &outputs);
+    assert!(matches!(
+        result,
+        Err(ExecutionError::SlotMismatch {
+            expected: 11_000_000,
+            actual: 11_000_001,
+        })
+    ));
+}
+
+#[test]
+fn rejects_other_block_hash() {
+    let (beacon_header, proof, mut outputs) = proven_block(payload_header(EMPTY_ROOT_HASH));
+    outputs.execution_header_root = FixedBytes::repeat_byte(0x66);
+
+    let result = ProvenExecutionHeader::new(&beacon_header, proof, &outputs);
+    assert!(matches!(
+        result,
+        Err(ExecutionError::BlockHashMismatch { .. })
+    ));
+}
+
+#[test]
+fn verifies_requested_account() {
+    let header = proven_header();
+    let address = Address::repeat_byte(0xaa);
+    let key = B256::repeat_byte(0x01);
+
+    let (account, slots) = empty_account_proof(address, address, vec![key])
+        .verify(&header)
+        .unwrap();
+    assert_eq!(account.address, address);
+    assert_eq!(slots.len(), 1);
+    assert_eq!(slots[0].key, key);
+}
+
+#[test]
+fn rejects_proof_for_other_account() {
+    let header = proven_header();
+    let proof = empty_account_proof(
+        Address::repeat_byte(0xaa),
+        Address::repeat_byte(0xbb),
+        vec![],
+    );
+
+    assert!(matches!(
+        proof.verify_account(&header),
+        Err(ExecutionError::InvalidResponse(_))
+    ));
+}
+
+#[test]
+fn rejects_proof_for_other_slots() {
+    let header = proven_header();
+    let address = Address::repeat_byte(0xaa);
+    let mut proof = empty_account_proof(address, address, vec![B256::repeat_byte(0x01)]);
+    proof.keys = vec![B256::repeat_byte(0x02)];
+
+    assert!(matches!(
+        proof.verify(&header),
+        Err(ExecutionError::InvalidResponse(_))
+    ));
+
+    // Missing storage proofs are rejected as well
+    proof.keys.push(B256::repeat_byte(0x01));
+    assert!(matches!(
+        proof.verify(&header),
+        Err(ExecutionError::InvalidResponse(_))
+    ));
+}
+
+#[test]
+fn rejects_forged_payload_fields() {
+    // A payload with the proven block hash but forged roots, as a malicious beacon RPC could return
+    let (beacon_header, _, outputs) = proven_block(payload_header(EMPTY_ROOT_HASH));
+    let (_, forged, _) = proven_block(payload_header(B256::repeat_byte(0x99)));
+    assert_eq!(
+        forged.execution_payload_header.block_hash(),
+        payload_header(EMPTY_ROOT_HASH).block_hash()
+    );
+
+    let result = ProvenExecutionHeader::new(&beacon_header, forged, &outputs);
+    assert!(matches!(result, Err(ExecutionError::PayloadProof(_))));
+}
@@ -0,0 +1,202 @@
+//! Checks that beacon state proofs are verified at the container depth of the proven state's fork
+//! and that historical summaries are indexed from the Sepolia Capella fork
+
+use alloy_primitives::FixedBytes;
+use bankai_core::{
+    fetcher::{
+        historical_block_proof::{HistoricalBlockProof, HistoricalRootSource},
+        state_proofs::{BeaconStateProofError, RandaoMixProof, ValidatorProof},
+    },
+    utils::constants::{CAPELLA_FORK_SLOT, ELECTRA_FORK_SLOT},
+};
+use beacon_types::{TreeHash, Validator};
+use sha2::{Digest, Sha256};
+
+/// Field index of `randao_mixes` in the `BeaconState` container
+const RANDAO_MIXES_FIELD_INDEX: u64 = 13;
+/// Field index of `validators` in the `BeaconState` container
+const VALIDATORS_FIELD_INDEX: u64 = 11;
+/// Field index of `historical_summaries` in the `BeaconState` container
+const HISTORICAL_SUMMARIES_FIELD_INDEX: u64 = 27;
+
+const DENEB_SLOT: u64 = ELECTRA_FORK_SLOT - 32;
+const ELECTRA_SLOT: u64 = ELECTRA_FORK_SLOT + 32;
+
+fn hash_pair(left: FixedBytes<32>, right: FixedBytes<32>) -> FixedBytes<32> {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    FixedBytes::from_slice(&hasher.finalize())
+}
+
+/// Builds a path of `depth` siblings and the root it leads to for the given leaf index
+fn path_and_root(
+    leaf: FixedBytes<32>,
+    index: u64,
+    depth: usize,
+) -> (Vec<FixedBytes<32>>, FixedBytes<32>) {
+    let path: Vec<FixedBytes<32>> = (0..depth)
+        .map(|i| FixedBytes::repeat_byte(i as u8 + 1))
+        .collect();
+
+    let mut root = leaf;
+    for (level, sibling) in path.iter().enumerate() {
+        root = if (index >> level) & 1 == 0 {
+            hash_pair(root, *sibling)
+        } else {
+            hash_pair(*sibling, root)
+        };
+    }
+
+    (path, root)
+}
+
+/// A randao mix proof for the given slot, whose path walks `state_depth` container levels
+fn randao_mix_proof(slot: u64, epoch: u64, state_depth: usize) -> (RandaoMixProof, FixedBytes<32>) {
+    let mix = FixedBytes::repeat_byte(0xaa);
+    let index = (epoch % (1 << 16)) | (RANDAO_MIXES_FIELD_INDEX << 16);
+    let (path, root) = path_and_root(mix, index, 16 + state_depth);
+
+    (
+        RandaoMixProof {
+            slot,
+            epoch,
+            mix,
+            path,
+        },
+        root,
+    )
+}
+
+#[test]
+fn verifies_at_the_fork_depth() {
+    let (proof, root) = randao_mix_proof(DENEB_SLOT, 1_000, 5);
+    proof.verify(root, DENEB_SLOT).unwrap();
+
+    let (proof, root) = randao_mix_proof(ELECTRA_SLOT, 1_000, 6);
+    proof.verify(root, ELECTRA_SLOT).unwrap();
+}
+
+#[test]
+fn rejects_path_of_other_fork_depth() {
+    // One level short against an Electra root, the upper index bits would select another field
+    let epoch = (1 << 15) + 7;
+    let (proof, root) = randao_mix_proof(ELECTRA_SLOT, epoch, 5);
+    assert!(matches!(
+        proof.verify(root, ELECTRA_SLOT),
+        Err(BeaconStateProofError::InvalidProof(_))
+    ));
+
+    let (proof, root) = randao_mix_proof(DENEB_SLOT, epoch, 6);
+    assert!(matches!(
+        proof.verify(root, DENEB_SLOT),
+        Err(BeaconStateProofError::InvalidProof(_))
+    ));
+}
+
+#[test]
+fn rejects_proof_of_other_slot() {
+    // A proof claiming a pre-Electra slot can't choose the depth used for an Electra state
+    let (proof, root) = randao_mix_proof(DENEB_SLOT, 1_000, 5);
+    assert!(matches!(
+        proof.verify(root, ELECTRA_SLOT),
+        Err(BeaconStateProofError::InvalidProof(_))
+    ));
+}
+
+#[test]
+fn rejects_validator_index_beyond_registry_limit() {
+    let validator = Validator::default();
+    let leaf = FixedBytes::from_slice(validator.tree_hash_root().as_slice());
+
+    // Index 2^40 selects the length chunk of the validators list
+    let validator_index = 1 << 40;
+    let (path, root) = path_and_root(
+        leaf,
+        validator_index | (VALIDATORS_FIELD_INDEX << 41),
+        41 + 6,
+    );
+    let proof = ValidatorProof {
+        slot: ELECTRA_SLOT,
+        validator_index,
+        validator: validator.clone(),
+        path,
+    };
+    assert!(matches!(
+        proof.verify(root, ELECTRA_SLOT),
+        Err(BeaconStateProofError::InvalidProof(_))
+    ));
+
+    let validator_index = (1 << 40) - 1;
+    let (path, root) = path_and_root(
+        leaf,
+        validator_index | (VALIDATORS_FIELD_INDEX << 41),
+        41 + 6,
+    );
+    let proof = ValidatorProof {
+        slot: ELECTRA_SLOT,
+        validator_index,
+        validator,
+        path,
+    };
+    proof.verify(root, ELECTRA_SLOT).unwrap();
+}
+
+/// A historical summaries proof whose path encodes `summary_index` and the summaries list length
+fn historical_block_proof(
+    slot: u64,
+    summary_index: u64,
+    n_summaries: u64,
+) -> (HistoricalBlockProof, FixedBytes<32>) {
+    let block_root = FixedBytes::repeat_byte(0xbb);
+    // block_roots vector, summary container, summaries list, length mix in, Electra container
+    let index = (slot % 8192) | (summary_index << 14) | (HISTORICAL_SUMMARIES_FIELD_INDEX << 39);
+    let (mut path, _) = path_and_root(block_root, index, 13 + 1 + 24 + 1 + 6);
+
+    let mut length = [0u8; 32];
+    length[..8].copy_from_slice(&n_summaries.to_le_bytes());
+    path[38] = FixedBytes::from(length);
+
+    let mut root = block_root;
+    for (level, sibling) in path.iter().enumerate() {
+        root = if (index >> level) & 1 == 0 {
+            hash_pair(root, *sibling)
+        } else {
+            hash_pair(*sibling, root)
+        };
+    }
+
+    (
+        HistoricalBlockProof {
+            slot,
+            block_root,
+            source: HistoricalRootSource::HistoricalSummaries,
+            path,
+        },
+        root,
+    )
+}
+
+#[test]
+fn indexes_historical_summaries_from_sepolia_capella() {
+    assert_eq!(CAPELLA_FORK_SLOT, 56832 * 32);
+
+    // A slot between the Sepolia and mainnet Capella forks, in the 536th summarized period
+    let slot = CAPELLA_FORK_SLOT + 536 * 8192 + 100;
+    let (proof, root) = historical_block_proof(slot, 536, 600);
+    proof.verify(root, ELECTRA_SLOT).unwrap();
+
+    // The summary has to be part of the state
+    let (proof, root) = historical_block_proof(slot, 536, 536);
+    assert!(matches!(
+        proof.verify(root, ELECTRA_SLOT),
+        Err(BeaconStateProofError::IndexOutOfBounds { index: 536, .. })
+    ));
+
+    // Slots before Capella have no historical summary
+    let (proof, root) = historical_block_proof(CAPELLA_FORK_SLOT - 1, 0, 600);
+    assert!(matches!(
+        proof.verify(root, ELECTRA_SLOT),
+        Err(BeaconStateProofError::InvalidProof(_))
+    ));
+}
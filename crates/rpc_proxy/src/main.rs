@@ -0,0 +1,505 @@
+//! Verifying JSON-RPC proxy
+//!
+//! Serves a subset of the Ethereum JSON-RPC API. Every call is forwarded to an upstream execution
+//! node, and the answer is only returned once it has been verified against the latest Bankai
+//! proven epoch stored in the database. The execution payload of the proven beacon block is bound
+//! to the proven `beacon_header_root` before any of its roots are used. Anything that can't be
+//! verified is refused with a JSON-RPC error.
+
+use std::{env, net::SocketAddr, sync::Arc};
+
+use alloy_consensus::{Header, Transaction as _};
+use alloy_network::eip2718::Encodable2718;
+use alloy_primitives::{keccak256, Address, B256, U256};
+use alloy_rlp::Encodable;
+use alloy_rpc_types::{Block, BlockNumberOrTag, BlockTransactions};
+use alloy_trie::root::ordered_trie_root_with_encoder;
+use axum::{body::Bytes, extract::State, routing::post, Json, Router};
+use bankai_core::{
+    clients::{
+        beacon_chain::BeaconRpcClient,
+        execution::{ExecutionRpcClient, ExecutionRpcError},
+    },
+    db::{self, EpochStore},
+    execution::{
+        account::{AccountProof, VerifiedAccount},
+        header_chain::fetch_headers_until,
+        ExecutionError, ProvenExecutionHeader,
+    },
+    fetcher::{
+        execution_header_input::{ExecutionHeaderError, ExecutionHeaderProof},
+        recursive_epoch_input::{BeaconHeader, RecursiveEpochOutput},
+    },
+    utils::config::BankaiConfig,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+/// Maximum distance of a block to the proven header, bounding the header chain that is fetched
+const MAX_HEADER_DISTANCE: u64 = 1024;
+/// Port the proxy listens on if `PROXY_PORT` is not set
+const DEFAULT_PORT: u16 = 8545;
+
+struct Proxy {
+    db: Box<dyn EpochStore>,
+    beacon: BeaconRpcClient,
+    execution: ExecutionRpcClient,
+    /// The latest proven execution header, refreshed when a newer proof is available
+    proven: RwLock<Option<ProvenExecutionHeader>>,
+}
+
+impl Proxy {
+    async fn handle_request(&self, request: Value) -> Value {
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        let method = request
+            .get("method")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let params = request.get("params").cloned().unwrap_or(json!([]));
+
+        match self.dispatch(method, &params).await {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(error) => {
+                warn!("❌ Refused {}: {}", method, error);
+                error_response(id, error.code(), error.to_string())
+            }
+        }
+    }
+
+    async fn dispatch(&self, method: &str, params: &Value) -> Result<Value, ProxyError> {
+        match method {
+            "eth_blockNumber" => {
+                let proven = self.proven_header().await?;
+                Ok(json!(format!("{:#x}", proven.block_number())))
+            }
+            "eth_getBalance" => {
+                let account = self
+                    .verified_account(param(params, 0)?, param(params, 1)?)
+                    .await?;
+                Ok(json!(account.balance))
+            }
+            "eth_getTransactionCount" => {
+                let account = self
+                    .verified_account(param(params, 0)?, param(params, 1)?)
+                    .await?;
+                Ok(json!(format!("{:#x}", account.nonce)))
+            }
+            "eth_getStorageAt" => {
+                self.get_storage_at(param(params, 0)?, param(params, 1)?, param(params, 2)?)
+                    .await
+            }
+            "eth_getTransactionByHash" => self.get_transaction_by_hash(param(params, 0)?).await,
+            "eth_getBlockByNumber" => {
+                self.get_block_by_number(param(params, 0)?, param(params, 1)?)
+                    .await
+            }
+            _ => Err(ProxyError::MethodNotSupported(method.to_string())),
+        }
+    }
+
+    /// Returns the latest proven execution header, fetching its payload when a new proof is found
+    async fn proven_header(&self) -> Result<ProvenExecutionHeader, ProxyError> {
+        let outputs = self
+            .db
+            .get_latest_proven_epoch_update()
+            .await
+            .map_err(|e| ProxyError::Database(e.to_string()))?
+            .and_then(|update| update.outputs)
+            .ok_or(ProxyError::NoProvenHeader)?;
+
+        if let Some(header) = self.proven.read().await.as_ref() {
+            if header.block_hash() == outputs.execution_header_root {
+                return Ok(header.clone());
+            }
+        }
+
+        let header = self.prove_execution_header(&outputs).await?;
+        info!(
+            "🔒 Proven execution header updated to block {}",
+            header.block_number()
+        );
+
+        *self.proven.write().await = Some(header.clone());
+        Ok(header)
+    }
+
+    /// Fetches the execution payload of a proven epoch and proves it against its outputs
+    async fn prove_execution_header(
+        &self,
+        outputs: &RecursiveEpochOutput,
+    ) -> Result<ProvenExecutionHeader, ProxyError> {
+        // The payload is only trusted once it is proven to be part of the proven beacon header
+        let block = self
+            .beacon
+            .get_block(outputs.beacon_height)
+            .await
+            .map_err(ExecutionHeaderError::Beacon)?;
+        let proof = ExecutionHeaderProof::from_block_body(&block.body, block.slot)?;
+        Ok(ProvenExecutionHeader::new(
+            &BeaconHeader::from(&block),
+            proof,
+            outputs,
+        )?)
+    }
+
+    /// Records the execution timestamp of proven epochs created before it was stored
+    ///
+    /// The timestamp is taken from the proven execution payload, so timestamp lookups only ever
+    /// see values bound to the proof.
+    async fn backfill_execution_timestamps(&self) -> Result<(), ProxyError> {
+        let updates = self
+            .db
+            .get_proven_epoch_updates_without_execution_timestamp()
+            .await
+            .map_err(|e| ProxyError::Database(e.to_string()))?;
+
+        for update in updates {
+            let Some(outputs) = update.outputs else {
+                continue;
+            };
+            let header = self.prove_execution_header(&outputs).await?;
+            self.db
+                .add_execution_timestamp(&update.uuid, header.timestamp())
+                .await
+                .map_err(|e| ProxyError::Database(e.to_string()))?;
+            info!(
+                "🕒 Recorded execution timestamp of epoch update {}",
+                update.uuid
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn verified_account(
+        &self,
+        address: Address,
+        tag: Option<BlockNumberOrTag>,
+    ) -> Result<VerifiedAccount, ProxyError> {
+        let proven = self.proven_header().await?;
+        require_proven_state(tag, &proven)?;
+
+        let proof = AccountProof::fetch_proof(&self.execution, &proven, address, vec![]).await?;
+        Ok(proof.verify_account(&proven)?)
+    }
+
+    async fn get_storage_at(
+        &self,
+        address: Address,
+        slot: U256,
+        tag: Option<BlockNumberOrTag>,
+    ) -> Result<Value, ProxyError> {
+        let proven = self.proven_header().await?;
+        require_proven_state(tag, &proven)?;
+
+        let key = B256::from(slot.to_be_bytes());
+        let proof = AccountProof::fetch_proof(&self.execution, &proven, address, vec![key]).await?;
+        let (_, slots) = proof.verify(&proven)?;
+        let value = slots
+            .first()
+            .ok_or_else(|| ProxyError::Unverifiable("Missing storage proof".to_string()))?
+            .value;
+
+        Ok(json!(B256::from(value.to_be_bytes())))
+    }
+
+    async fn get_transaction_by_hash(&self, hash: B256) -> Result<Value, ProxyError> {
+        let proven = self.proven_header().await?;
+
+        // The absence of a transaction can't be proven, so unknown transactions are refused
+        let transaction = self
+            .execution
+            .get_transaction_by_hash(hash)
+            .await?
+            .ok_or_else(|| ProxyError::Unverifiable(format!("Transaction {} not found", hash)))?;
+        let block_number = transaction
+            .block_number
+            .ok_or_else(|| ProxyError::Unverifiable(format!("Transaction {} is pending", hash)))?;
+
+        let block = self.verified_block(block_number, &proven).await?;
+        let BlockTransactions::Full(transactions) = block.transactions else {
+            return Err(ProxyError::Unverifiable(
+                "Block is missing full transactions".to_string(),
+            ));
+        };
+
+        let transaction = transactions
+            .into_iter()
+            .find(|tx| *tx.inner.inner().tx_hash() == hash)
+            .ok_or_else(|| {
+                ProxyError::Unverifiable(format!(
+                    "Transaction {} is not part of block {}",
+                    hash, block_number
+                ))
+            })?;
+
+        Ok(serde_json::to_value(transaction)?)
+    }
+
+    async fn get_block_by_number(
+        &self,
+        tag: BlockNumberOrTag,
+        full: Option<bool>,
+    ) -> Result<Value, ProxyError> {
+        let proven = self.proven_header().await?;
+        let block_number = resolve_block(tag, &proven)?;
+
+        let mut block = self.verified_block(block_number, &proven).await?;
+        if !full.unwrap_or(false) {
+            block.transactions = BlockTransactions::Hashes(block.transactions.hashes().collect());
+        }
+
+        Ok(serde_json::to_value(block)?)
+    }
+
+    /// Fetches a full block and verifies it against the header chain of the proven header
+    async fn verified_block(
+        &self,
+        block_number: u64,
+        proven: &ProvenExecutionHeader,
+    ) -> Result<Block, ProxyError> {
+        if block_number + MAX_HEADER_DISTANCE < proven.block_number() {
+            return Err(ProxyError::Unverifiable(format!(
+                "Block {} is more than {} blocks behind the proven block {}",
+                block_number,
+                MAX_HEADER_DISTANCE,
+                proven.block_number()
+            )));
+        }
+
+        let headers = fetch_headers_until(&self.execution, block_number, proven).await?;
+        let header = &headers[0];
+
+        let mut block = self.execution.get_block(block_number, true).await?;
+        if block.header.inner != *header || block.header.hash != header.hash_slow() {
+            return Err(ProxyError::Unverifiable(format!(
+                "Header of block {} does not match the verified header",
+                block_number
+            )));
+        }
+        if !block.uncles.is_empty() {
+            return Err(ProxyError::Unverifiable(
+                "Post-merge blocks can't have uncles".to_string(),
+            ));
+        }
+
+        verify_transactions(&mut block, header)?;
+
+        // Blocks from Shanghai on have to include their withdrawals, earlier blocks can't have any
+        match (&block.withdrawals, header.withdrawals_root) {
+            (Some(withdrawals), Some(withdrawals_root)) => {
+                let root = ordered_trie_root_with_encoder(withdrawals, |withdrawal, buf| {
+                    withdrawal.encode(buf)
+                });
+                if root != withdrawals_root {
+                    return Err(ProxyError::Unverifiable(format!(
+                        "Withdrawals of block {} do not match the withdrawals root",
+                        block_number
+                    )));
+                }
+            }
+            (None, None) => {}
+            _ => {
+                return Err(ProxyError::Unverifiable(format!(
+                    "Withdrawals of block {} do not match the header",
+                    block_number
+                )));
+            }
+        }
+
+        // The size and total difficulty are not committed to by the header, so they are dropped
+        block.header.size = None;
+        block.header.total_difficulty = None;
+
+        Ok(block)
+    }
+}
+
+/// Verifies the transactions of a block against its header
+///
+/// The transaction root only covers the signed transactions. The hashes and signers are checked
+/// against the encoding, the remaining derived fields are recomputed from the verified header.
+fn verify_transactions(block: &mut Block, header: &Header) -> Result<(), ProxyError> {
+    let BlockTransactions::Full(transactions) = &mut block.transactions else {
+        return Err(ProxyError::Unverifiable(
+            "Block is missing full transactions".to_string(),
+        ));
+    };
+
+    let root =
+        ordered_trie_root_with_encoder(transactions, |tx, buf| tx.inner.inner().encode_2718(buf));
+    if root != header.transactions_root {
+        return Err(ProxyError::Unverifiable(format!(
+            "Transactions of block {} do not match the transactions root",
+            header.number
+        )));
+    }
+
+    let block_hash = header.hash_slow();
+    for (index, tx) in transactions.iter_mut().enumerate() {
+        let envelope = tx.inner.inner();
+        if *envelope.tx_hash() != keccak256(envelope.encoded_2718()) {
+            return Err(ProxyError::Unverifiable(format!(
+                "Transaction hash mismatch at index {}",
+                index
+            )));
+        }
+
+        let signer = envelope.recover_signer().map_err(|_| {
+            ProxyError::Unverifiable(format!("Invalid signature at index {}", index))
+        })?;
+        if signer != tx.inner.signer() {
+            return Err(ProxyError::Unverifiable(format!(
+                "Sender mismatch at index {}",
+                index
+            )));
+        }
+
+        tx.effective_gas_price = Some(envelope.effective_gas_price(header.base_fee_per_gas));
+        tx.block_hash = Some(block_hash);
+        tx.block_number = Some(header.number);
+        tx.transaction_index = Some(index as u64);
+    }
+
+    Ok(())
+}
+
+/// Resolves a block tag to a block number covered by the proven header
+fn resolve_block(tag: BlockNumberOrTag, proven: &ProvenExecutionHeader) -> Result<u64, ProxyError> {
+    match tag {
+        BlockNumberOrTag::Latest | BlockNumberOrTag::Safe | BlockNumberOrTag::Finalized => {
+            Ok(proven.block_number())
+        }
+        BlockNumberOrTag::Number(number) if number <= proven.block_number() => Ok(number),
+        tag => Err(ProxyError::Unverifiable(format!(
+            "Block {} is not covered by the proven block {}",
+            tag,
+            proven.block_number()
+        ))),
+    }
+}
+
+/// State reads can only be verified against the state root of the proven header
+fn require_proven_state(
+    tag: Option<BlockNumberOrTag>,
+    proven: &ProvenExecutionHeader,
+) -> Result<(), ProxyError> {
+    let block_number = resolve_block(tag.unwrap_or_default(), proven)?;
+    if block_number != proven.block_number() {
+        return Err(ProxyError::Unverifiable(format!(
+            "State is only verified at the proven block {}",
+            proven.block_number()
+        )));
+    }
+    Ok(())
+}
+
+/// Deserializes a positional parameter. Missing parameters are passed as `null`.
+fn param<T: DeserializeOwned>(params: &Value, index: usize) -> Result<T, ProxyError> {
+    let value = params.get(index).cloned().unwrap_or(Value::Null);
+    serde_json::from_value(value)
+        .map_err(|e| ProxyError::InvalidParams(format!("Parameter {}: {}", index, e)))
+}
+
+/// Builds a JSON-RPC error response
+fn error_response(id: Value, code: i64, message: String) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message }
+    })
+}
+
+async fn handle(State(proxy): State<Arc<Proxy>>, body: Bytes) -> Json<Value> {
+    // Malformed bodies are answered with a JSON-RPC parse error instead of an HTTP error
+    let request = match serde_json::from_slice::<Value>(&body) {
+        Ok(request) => request,
+        Err(e) => {
+            warn!("❌ Malformed request: {}", e);
+            return Json(error_response(
+                Value::Null,
+                -32700,
+                format!("Parse error: {}", e),
+            ));
+        }
+    };
+
+    match request {
+        Value::Array(requests) => {
+            let mut responses = Vec::with_capacity(requests.len());
+            for request in requests {
+                responses.push(proxy.handle_request(request).await);
+            }
+            Json(Value::Array(responses))
+        }
+        request => Json(proxy.handle_request(request).await),
+    }
+}
+
+#[derive(Debug, Error)]
+enum ProxyError {
+    #[error("Method not supported by the verifying proxy: {0}")]
+    MethodNotSupported(String),
+    #[error("Invalid params: {0}")]
+    InvalidParams(String),
+    #[error("No proven execution header available")]
+    NoProvenHeader,
+    #[error("Unverifiable response: {0}")]
+    Unverifiable(String),
+    #[error("Verification failed: {0}")]
+    Execution(#[from] ExecutionError),
+    #[error("Execution RPC error: {0}")]
+    Rpc(#[from] ExecutionRpcError),
+    #[error("Execution header error: {0}")]
+    ExecutionHeader(#[from] ExecutionHeaderError),
+    #[error("Database error: {0}")]
+    Database(String),
+    #[error("Serialization error: {0}")]
+    Serialize(#[from] serde_json::Error),
+}
+
+impl ProxyError {
+    /// Returns the JSON-RPC error code of the error
+    fn code(&self) -> i64 {
+        match self {
+            ProxyError::MethodNotSupported(_) => -32601,
+            ProxyError::InvalidParams(_) => -32602,
+            _ => -32000,
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt().init();
+    dotenv::from_filename(".env.sepolia").ok();
+
+    let config = BankaiConfig::default();
+    let db = db::connect(&config.database_url, config.proof_blob_dir.as_deref()).await?;
+    let beacon = BeaconRpcClient::new(env::var("BEACON_RPC_URL")?, config);
+    let execution = ExecutionRpcClient::new(env::var("EXECUTION_RPC_URL")?)?;
+    let port = env::var("PROXY_PORT")
+        .ok()
+        .and_then(|port| port.parse().ok())
+        .unwrap_or(DEFAULT_PORT);
+
+    let proxy = Arc::new(Proxy {
+        db,
+        beacon,
+        execution,
+        proven: RwLock::new(None),
+    });
+    proxy.backfill_execution_timestamps().await?;
+
+    let app = Router::new().route("/", post(handle)).with_state(proxy);
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    info!("🚀 Verifying RPC proxy listening on {}", addr);
+
+    axum::serve(listener, app).await?;
+
+    Ok(())
+}
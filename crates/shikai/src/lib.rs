@@ -0,0 +1,156 @@
+//! Shikai SDK
+//!
+//! Verifies Bankai recursive epoch proofs and exposes the proven beacon and execution header
+//! roots. The roots returned here are the trust anchors for the getters of `bankai_core`, e.g.
+//! `ProvenExecutionHeader` or the beacon state proofs.
+
+use alloy_primitives::FixedBytes;
+use bankai_core::fetcher::recursive_epoch_input::RecursiveEpochOutput;
+use starknet_crypto::Felt;
+use swiftness::TransformTo;
+use swiftness_air::layout::dynamic::Layout;
+use swiftness_proof_parser::parse;
+use swiftness_stark::types::StarkProof;
+use thiserror::Error;
+
+/// Program hash of the bootloader the recursive proofs are generated with
+pub const BOOTLOADER_PROGRAM_HASH: &str =
+    "0x5AB580B04E3532B6B18F81CFA654A05E29DD8E2352D88DF1E765A84072DB07";
+
+/// Minimum security of an accepted proof, matching the recursive verifier in `stone.cairo`
+pub const SECURITY_BITS: u32 = 96;
+
+/// Number of output felts written by the recursive epoch program
+pub const EPOCH_OUTPUT_LEN: usize = RecursiveEpochOutput::N_FELTS;
+
+/// A recursive epoch proof that has been verified against a Bankai program hash
+#[derive(Debug, Clone)]
+pub struct VerifiedEpoch {
+    /// Hash of the Bankai program that produced the output
+    pub program_hash: Felt,
+    /// The decoded program output
+    pub output: RecursiveEpochOutput,
+}
+
+impl VerifiedEpoch {
+    /// Returns the root of the proven beacon header
+    pub fn beacon_header_root(&self) -> FixedBytes<32> {
+        self.output.beacon_header_root
+    }
+
+    /// Returns the state root of the proven beacon header
+    pub fn beacon_state_root(&self) -> FixedBytes<32> {
+        self.output.beacon_state_root
+    }
+
+    /// Returns the slot of the proven beacon header
+    pub fn beacon_height(&self) -> u64 {
+        self.output.beacon_height
+    }
+
+    /// Returns the block hash of the proven execution header
+    pub fn execution_header_root(&self) -> FixedBytes<32> {
+        self.output.execution_header_root
+    }
+
+    /// Returns the block number of the proven execution header
+    pub fn execution_header_height(&self) -> u64 {
+        self.output.execution_header_height
+    }
+}
+
+/// Verifies a Bankai recursive epoch proof and decodes its output
+///
+/// The proof is a bootloader proof, whose output is `[1, 15, program_hash, ...epoch_output]`.
+/// Both the bootloader hash and the Bankai program hash are checked. The proof is verified at
+/// `SECURITY_BITS`, and proofs whose config provides less security are rejected.
+///
+/// # Arguments
+/// * `proof_json` - The recursive proof, as returned by the prover
+/// * `program_hash` - The hash of the Bankai recursive epoch program
+///
+/// # Returns
+/// * `Result<VerifiedEpoch, ShikaiError>` - The verified epoch or an error
+pub fn verify_recursive_proof(
+    proof_json: &str,
+    program_hash: Felt,
+) -> Result<VerifiedEpoch, ShikaiError> {
+    let stark_proof: StarkProof = parse(proof_json.to_string())
+        .map_err(|e| ShikaiError::Parse(e.to_string()))?
+        .transform_to();
+
+    check_security_bits(stark_proof.config.security_bits())?;
+    let (proof_program_hash, output) = stark_proof
+        .verify::<Layout>(Felt::from(SECURITY_BITS))
+        .map_err(|e| ShikaiError::Verification(e.to_string()))?;
+
+    let bootloader_hash = Felt::from_hex(BOOTLOADER_PROGRAM_HASH).unwrap();
+    if proof_program_hash != bootloader_hash {
+        return Err(ShikaiError::ProgramHashMismatch {
+            expected: bootloader_hash,
+            actual: proof_program_hash,
+        });
+    }
+
+    // The bootloader output contains the number of tasks, the task output size and its program hash
+    let task_output_size = Felt::from(EPOCH_OUTPUT_LEN + 2);
+    match output.as_slice() {
+        [n_tasks, size, task_program_hash, epoch_output @ ..]
+            if *n_tasks == Felt::ONE && *size == task_output_size =>
+        {
+            if *task_program_hash != program_hash {
+                return Err(ShikaiError::ProgramHashMismatch {
+                    expected: program_hash,
+                    actual: *task_program_hash,
+                });
+            }
+
+            let output = RecursiveEpochOutput::from_felts(epoch_output)
+                .map_err(|e| ShikaiError::InvalidOutput(e.to_string()))?;
+
+            Ok(VerifiedEpoch {
+                program_hash,
+                output,
+            })
+        }
+        _ => Err(ShikaiError::InvalidOutput(format!(
+            "Unexpected bootloader output of {} felts",
+            output.len()
+        ))),
+    }
+}
+
+/// Checks that a proof config provides at least `SECURITY_BITS` of security
+///
+/// The security of a proof is derived from its own config, i.e.
+/// `n_queries * log_n_cosets + proof_of_work_bits`, so it must be bounded by a fixed value.
+///
+/// # Arguments
+/// * `security_bits` - The security bits of the proof config
+///
+/// # Returns
+/// * `Result<(), ShikaiError>` - An error if the config is weaker than `SECURITY_BITS`
+pub fn check_security_bits(security_bits: Felt) -> Result<(), ShikaiError> {
+    if security_bits < Felt::from(SECURITY_BITS) {
+        return Err(ShikaiError::InsufficientSecurity {
+            required: SECURITY_BITS,
+            actual: security_bits,
+        });
+    }
+    Ok(())
+}
+
+/// Possible errors that can occur while verifying a recursive proof
+#[derive(Debug, Error)]
+pub enum ShikaiError {
+    #[error("Failed to parse proof: {0}")]
+    Parse(String),
+    #[error("Proof verification failed: {0}")]
+    Verification(String),
+    #[error("Program hash mismatch: expected {expected:#x}, got {actual:#x}")]
+    ProgramHashMismatch { expected: Felt, actual: Felt },
+    #[error("Insufficient proof security: required {required} bits, got {actual}")]
+    InsufficientSecurity { required: u32, actual: Felt },
+    #[error("Invalid proof output: {0}")]
+    InvalidOutput(String),
+}
@@ -0,0 +1,22 @@
+use shikai::{check_security_bits, ShikaiError, SECURITY_BITS};
+use starknet_crypto::Felt;
+
+#[test]
+fn accepts_required_security() {
+    assert!(check_security_bits(Felt::from(SECURITY_BITS)).is_ok());
+    assert!(check_security_bits(Felt::from(128u64)).is_ok());
+}
+
+#[test]
+fn rejects_insufficient_security() {
+    for security_bits in [0u64, 1, SECURITY_BITS as u64 - 1] {
+        let security_bits = Felt::from(security_bits);
+        match check_security_bits(security_bits) {
+            Err(ShikaiError::InsufficientSecurity { required, actual }) => {
+                assert_eq!(required, SECURITY_BITS);
+                assert_eq!(actual, security_bits);
+            }
+            other => panic!("insufficient security accepted: {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS proofs (
+    id BIGSERIAL PRIMARY KEY,
+    proof TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS epoch_updates (
+    uuid TEXT PRIMARY KEY,
+    epoch_number BIGINT NOT NULL,
+    slot_number BIGINT NOT NULL,
+    outputs TEXT,
+    atlantic_id TEXT,
+    proof_id BIGINT REFERENCES proofs (id),
+    status TEXT NOT NULL,
+    error_reason TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_epoch_updates_slot_number ON epoch_updates (slot_number);
@@ -0,0 +1,15 @@
+-- Columns for the fields of `outputs` that are queried, so lookups don't parse the JSON
+ALTER TABLE epoch_updates
+    ADD COLUMN beacon_height BIGINT,
+    ADD COLUMN execution_height BIGINT,
+    ADD COLUMN execution_block_hash TEXT,
+    ADD COLUMN beacon_header_root TEXT,
+    ADD COLUMN current_committee_hash TEXT,
+    ADD COLUMN next_committee_hash TEXT;
+
+CREATE INDEX idx_epoch_updates_beacon_height ON epoch_updates (beacon_height);
+CREATE INDEX idx_epoch_updates_execution_height ON epoch_updates (execution_height);
+CREATE INDEX idx_epoch_updates_execution_block_hash ON epoch_updates (execution_block_hash);
+CREATE INDEX idx_epoch_updates_beacon_header_root ON epoch_updates (beacon_header_root);
+CREATE INDEX idx_epoch_updates_current_committee_hash ON epoch_updates (current_committee_hash);
+CREATE INDEX idx_epoch_updates_next_committee_hash ON epoch_updates (next_committee_hash);
@@ -0,0 +1,9 @@
+-- Populates the output columns of updates created before they existed
+UPDATE epoch_updates
+SET beacon_height = (outputs::jsonb ->> 'beacon_height')::BIGINT,
+    execution_height = (outputs::jsonb ->> 'execution_header_height')::BIGINT,
+    execution_block_hash = lower(outputs::jsonb ->> 'execution_header_root'),
+    beacon_header_root = lower(outputs::jsonb ->> 'beacon_header_root'),
+    current_committee_hash = lower(outputs::jsonb ->> 'current_committee_hash'),
+    next_committee_hash = lower(outputs::jsonb ->> 'next_committee_hash')
+WHERE outputs IS NOT NULL;
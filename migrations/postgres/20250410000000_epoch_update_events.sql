@@ -0,0 +1,12 @@
+-- History of the status transitions of the epoch updates. `from_status` is NULL for the creation
+-- of an update, `created_at` is in unix milliseconds.
+CREATE TABLE epoch_update_events (
+    id BIGSERIAL PRIMARY KEY,
+    uuid TEXT NOT NULL REFERENCES epoch_updates (uuid),
+    from_status TEXT,
+    to_status TEXT NOT NULL,
+    error_reason TEXT,
+    created_at BIGINT NOT NULL
+);
+
+CREATE INDEX idx_epoch_update_events_uuid ON epoch_update_events (uuid);
@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS proofs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    proof TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS epoch_updates (
+    uuid TEXT PRIMARY KEY,
+    epoch_number INTEGER NOT NULL,
+    slot_number INTEGER NOT NULL,
+    outputs TEXT,
+    atlantic_id TEXT,
+    proof_id INTEGER REFERENCES proofs (id),
+    status TEXT NOT NULL,
+    error_reason TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_epoch_updates_slot_number ON epoch_updates (slot_number);
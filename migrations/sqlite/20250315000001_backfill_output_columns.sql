@@ -0,0 +1,9 @@
+-- Populates the output columns of updates created before they existed
+UPDATE epoch_updates
+SET beacon_height = json_extract(outputs, '$.beacon_height'),
+    execution_height = json_extract(outputs, '$.execution_header_height'),
+    execution_block_hash = lower(json_extract(outputs, '$.execution_header_root')),
+    beacon_header_root = lower(json_extract(outputs, '$.beacon_header_root')),
+    current_committee_hash = lower(json_extract(outputs, '$.current_committee_hash')),
+    next_committee_hash = lower(json_extract(outputs, '$.next_committee_hash'))
+WHERE outputs IS NOT NULL AND json_valid(outputs);
@@ -0,0 +1,4 @@
+-- Timestamp of the proven execution header, it isn't part of the outputs so it is recorded separately
+ALTER TABLE epoch_updates ADD COLUMN execution_timestamp INTEGER;
+
+CREATE INDEX idx_epoch_updates_execution_timestamp ON epoch_updates (execution_timestamp);
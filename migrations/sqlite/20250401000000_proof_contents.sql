@@ -0,0 +1,15 @@
+-- Content-addressed proofs, keyed by the SHA256 hash of the proof JSON. The zstd compressed proof
+-- is either inlined in `data` or written to the blob store, referenced by `blob_path`.
+CREATE TABLE proof_contents (
+    hash TEXT PRIMARY KEY,
+    data BLOB,
+    blob_path TEXT,
+    size INTEGER NOT NULL,
+    CHECK (data IS NOT NULL OR blob_path IS NOT NULL)
+);
+
+-- `proofs.proof` only holds proofs stored before content addressing, newer proofs reference their
+-- content and leave it empty
+ALTER TABLE proofs ADD COLUMN content_hash TEXT REFERENCES proof_contents (hash);
+
+CREATE UNIQUE INDEX idx_proofs_content_hash ON proofs (content_hash);